@@ -41,6 +41,50 @@ declare global {
 			date: string;
 			v8: string;
 			files: string[];
+			security?: boolean;
+		}
+
+		interface InstalledVersion {
+			/** the installation name, `{version}` or `{version}-{arch}` for a non-default arch */
+			name: string;
+			version: string;
+			arch?: string;
+			installedAt?: number;
+			source?: string;
+			checksum?: string;
+			size: number;
+			npm?: string;
+		}
+
+		interface VersionIntegrity {
+			version: string;
+			status: 'ok' | 'missing' | 'corrupt' | 'unverified';
+			modified: string[];
+			missing: string[];
+			message?: string;
+		}
+
+		interface ExternalVersion {
+			tool: 'nvm' | 'fnm' | 'volta' | 'n' | 'asdf';
+			version: string;
+			path: string;
+			valid: boolean;
+			installed: boolean;
+			isDefault: boolean;
+		}
+
+		type ImportMode = 'copy' | 'hardlink' | 'register';
+
+		interface ImportResult {
+			tool: string;
+			version: string;
+			error?: string;
+		}
+
+		interface SecurityAlert {
+			version: string;
+			securityVersion: string;
+			sources: string[];
 		}
 
 		type Versions = Array<Version>;
@@ -60,6 +104,15 @@ declare global {
 		interface Setting {
 			closer: Closer;
 			directory: string;
+			sources?: string[];
+			/** installed globally with every new version, e.g. `typescript`, `pnpm@^8` */
+			default_packages?: string[];
+			/** the version files kept up to date for projects besides `.nvmdrc` */
+			version_files?: VersionFile[];
+			/** the command to open a terminal, `{path}` is replaced with the project path */
+			terminal?: string;
+			/** the command to open an editor, `{path}` is replaced with the project path */
+			editor?: string;
 			enable_silent_start?: boolean;
 			locale: string;
 			mirror: string;
@@ -68,29 +121,195 @@ declare global {
 			theme: Themes;
 		}
 
+		interface MirrorBenchmark {
+			mirror: string;
+			latency?: number;
+			throughput?: number;
+			elapsed?: number;
+			error?: string;
+		}
+
 		// type UpdateInfo = ElectronUpdateInfo | "update-not-available";
 
 		interface Project {
+			/** the stable unique id, assigned by the backend when saved */
+			id?: string;
 			name: string;
 			path: string;
 			version?: string;
+			/** the version files kept up to date besides `.nvmdrc`, the global setting when omitted */
+			versionFiles?: VersionFile[];
+			/** the environment variables applied when running commands or opening terminals */
+			env?: Record<string, string>;
+			/** mirror the environment variables into `.nvmdenv` for the shim */
+			envFile?: boolean;
 			active: boolean;
 			createAt: string;
 			updateAt: string;
 		}
 
+		type VersionFile =
+			| '.nvmdrc'
+			| '.nvmrc'
+			| '.node-version'
+			| 'package.json#volta';
+
 		interface PInfo {
 			path: string;
 			version?: string;
+			source?: string;
+			spec?: string;
+		}
+
+		type HealthIssueKind =
+			| 'missingPath'
+			| 'notInstalled'
+			| 'missingGroup'
+			| 'drifted';
+
+		interface Script {
+			name: string;
+			command: string;
+		}
+
+		type ProcessStatus = 'running' | 'exited' | 'stopped';
+
+		interface ProcessInfo {
+			id: string;
+			path: string;
+			script: string;
+			/** the node version used */
+			version: string;
+			pid?: number;
+			status: ProcessStatus;
+			code?: number;
+			startedAt: number;
+		}
+
+		interface OutputLine {
+			id: string;
+			stream: 'stdout' | 'stderr';
+			line: string;
+		}
+
+		interface SyncResult {
+			path: string;
+			error?: string;
+			/** the project directory no longer exists, the project is skipped */
+			missing: boolean;
+			/** the written version files were restored because of other failures */
+			rolledBack: boolean;
+		}
+
+		interface BatchUpdate {
+			results: SyncResult[];
+			/** `false` when rolled back */
+			applied: boolean;
+		}
+
+		interface MissingVersion {
+			/** the installation name, `{version}` or `{version}-{arch}` */
+			name: string;
+			usages: Usage[];
+		}
+
+		interface RequiredInstall {
+			name: string;
+			path?: string;
+			error?: string;
+		}
+
+		interface RequiredProgress {
+			name: string;
+			current: number;
+			total: number;
+		}
+
+		interface HealthIssue {
+			kind: HealthIssueKind;
+			name: string;
+			path: string;
+			/** the version expected by `projects.json` */
+			version?: string;
+			group?: string;
+			/** the content of the drifted version file */
+			actual?: string;
+		}
+
+		interface DetectedVersion {
+			source: string;
+			spec: string;
+			version?: string;
 		}
 
 		interface Group {
 			name: string;
 			desc?: string;
 			version: string;
+			/** the ids of the member projects */
 			projects: string[];
 		}
 
+		interface Alias {
+			name: string;
+			version: string;
+		}
+
+		interface Aliases {
+			list?: Alias[];
+			default?: string;
+		}
+
+		interface Usage {
+			version: string;
+			kind: 'default' | 'project' | 'group' | 'alias';
+			name: string;
+			/** the project id */
+			id?: string;
+			path?: string;
+		}
+
+		interface PruneOptions {
+			unused?: boolean;
+			keepLatest?: number;
+			dryRun?: boolean;
+		}
+
+		interface PruneCandidate {
+			version: string;
+			reason: 'unused' | 'retention';
+			size: number;
+			error?: string;
+		}
+
+		interface PruneReport {
+			candidates: PruneCandidate[];
+			reclaimable: number;
+			dryRun: boolean;
+		}
+
+		interface GlobalPackage {
+			name: string;
+			version?: string;
+			linked: boolean;
+			bins: string[];
+		}
+
+		interface PackageResult {
+			name: string;
+			version?: string;
+			skipped: boolean;
+			error?: string;
+		}
+
+		interface PackageProgress {
+			from?: string;
+			to: string;
+			name: string;
+			current: number;
+			total: number;
+		}
+
 		interface ConfigrationExport {
 			color?: string;
 			mirrors?: string;
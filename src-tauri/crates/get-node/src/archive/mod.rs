@@ -1,8 +1,12 @@
 mod tarball;
 mod zip;
 
-use super::{node, Proxy};
+use super::{
+    node::{self, Node},
+    Proxy,
+};
 use anyhow::Result;
+use node_semver::Version;
 use std::{path::PathBuf, time::Duration};
 
 /// get progress
@@ -40,7 +44,33 @@ pub struct FetchConfig {
     pub on_progress: Box<OnProgress>,
 }
 
-fn create_client(
+pub struct FetchResult {
+    /// the installation path
+    pub path: String,
+
+    /// the downloaded archive file name
+    pub archive: String,
+
+    /// the sha256 checksum of the downloaded archive
+    pub checksum: String,
+}
+
+/// get the installation directory name of the version & arch
+/// the platform default arch is installed as `{version}`, other archs as `{version}-{arch}`
+pub fn install_name(version: &str, arch: Option<&str>) -> Result<String> {
+    let Some(arch) = arch else {
+        return Ok(version.to_string());
+    };
+
+    let parsed = Version::parse(version)?;
+    if Node::archive_basename(&parsed, None) == Node::archive_basename(&parsed, Some(arch.into())) {
+        Ok(version.to_string())
+    } else {
+        Ok(format!("{version}-{arch}"))
+    }
+}
+
+pub(crate) fn create_client(
     proxy: Option<Proxy>,
     no_proxy: Option<bool>,
     timeout: Duration,
@@ -96,7 +126,7 @@ cfg_if::cfg_if! {
         ///
         /// On Windows, the preferred format is zip. On Unixes, the preferred format
         /// is tarball.
-        pub async fn fetch_native(config: FetchConfig) -> Result<String> {
+        pub async fn fetch_native(config: FetchConfig) -> Result<FetchResult> {
             tarball::fetch(config).await
         }
     } else if #[cfg(windows)] {
@@ -105,10 +135,29 @@ cfg_if::cfg_if! {
         ///
         /// On Windows, the preferred format is zip. On Unixes, the preferred format
         /// is tarball.
-        pub async fn fetch_native(config: FetchConfig) -> Result<String> {
+        pub async fn fetch_native(config: FetchConfig) -> Result<FetchResult> {
             zip::fetch(config).await
         }
     } else {
         compile_error!("Unsupported OS (expected 'unix' or 'windows').");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::NODE_DISTRO_ARCH;
+
+    #[test]
+    fn test_install_name() {
+        assert_eq!(install_name("20.2.3", None).unwrap(), "20.2.3");
+        assert_eq!(
+            install_name("20.2.3", Some(NODE_DISTRO_ARCH)).unwrap(),
+            "20.2.3"
+        );
+        assert_eq!(
+            install_name("20.2.3", Some("s390x")).unwrap(),
+            "20.2.3-s390x"
+        );
+    }
+}
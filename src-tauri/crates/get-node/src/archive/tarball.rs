@@ -2,6 +2,7 @@ use anyhow::{bail, Result};
 use async_compression::tokio::bufread::GzipDecoder;
 use futures_util::StreamExt;
 use node_semver::Version;
+use sha2::{Digest, Sha256};
 use std::{path::PathBuf, time::Duration};
 use tokio::{
     fs::{remove_dir_all, remove_file, rename, File},
@@ -9,9 +10,9 @@ use tokio::{
 };
 use tokio_tar::Archive;
 
-use super::{create_client, node::*, send, FetchConfig};
+use super::{create_client, install_name, node::*, send, FetchConfig, FetchResult};
 
-pub async fn fetch(config: FetchConfig) -> Result<String> {
+pub async fn fetch(config: FetchConfig) -> Result<FetchResult> {
     let FetchConfig {
         dest,
         mirror,
@@ -24,6 +25,7 @@ pub async fn fetch(config: FetchConfig) -> Result<String> {
         on_progress,
     } = config;
 
+    let target = install_name(&version, arch.as_deref())?;
     let (name, full_name) = Node::archive_filename(&Version::parse(&version)?, arch);
     let url = format!("{}/v{}/{}", mirror, &version, &full_name);
     // timeout default value is `20s`
@@ -47,6 +49,7 @@ pub async fn fetch(config: FetchConfig) -> Result<String> {
     // start to download file
     let mut temp_file = File::create(&temp_file_path).await?;
     let mut stream = response.bytes_stream();
+    let mut hasher = Sha256::new();
     // write stream buffer to file
     while let Some(chunk) = match cancel_signal.as_mut() {
         Some(cancel_receiver) => {
@@ -64,6 +67,7 @@ pub async fn fetch(config: FetchConfig) -> Result<String> {
     } {
         let chunk = chunk?;
         downloaded_size += chunk.len();
+        hasher.update(&chunk);
         temp_file.write_all(&chunk).await?;
         on_progress("download", downloaded_size as usize, total_size as usize);
     }
@@ -109,10 +113,14 @@ pub async fn fetch(config: FetchConfig) -> Result<String> {
     }
 
     let (_rename_future, _remove_future) = tokio::join!(
-        rename(dest.join(&name), dest.join(&version)),
+        rename(dest.join(&name), dest.join(&target)),
         remove_file(temp_file_path)
     );
 
-    let path = dest.join(&version).to_string_lossy().to_string();
-    Ok(path)
+    let path = dest.join(&target).to_string_lossy().to_string();
+    Ok(FetchResult {
+        path,
+        archive: full_name,
+        checksum: format!("{:x}", hasher.finalize()),
+    })
 }
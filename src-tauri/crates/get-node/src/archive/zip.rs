@@ -4,15 +4,16 @@ use anyhow::{bail, Result};
 use async_zip::tokio::read::seek::ZipFileReader;
 use futures_util::StreamExt;
 use node_semver::Version;
+use sha2::{Digest, Sha256};
 use tokio::{
     fs::{create_dir_all, remove_dir_all, remove_file, rename, File, OpenOptions},
     io::{AsyncWriteExt, BufReader},
 };
 use tokio_util::compat::TokioAsyncWriteCompatExt;
 
-use super::{create_client, node::*, send, FetchConfig, PathBuf};
+use super::{create_client, install_name, node::*, send, FetchConfig, FetchResult, PathBuf};
 
-pub async fn fetch(config: FetchConfig) -> Result<String> {
+pub async fn fetch(config: FetchConfig) -> Result<FetchResult> {
     let FetchConfig {
         dest,
         mirror,
@@ -25,6 +26,7 @@ pub async fn fetch(config: FetchConfig) -> Result<String> {
         on_progress,
     } = config;
 
+    let target = install_name(&version, arch.as_deref())?;
     let (name, full_name) = Node::archive_filename(&Version::parse(&version)?, arch);
     let url = format!("{}/v{}/{}", mirror, &version, &full_name);
     // timeout default value is `20s`
@@ -46,6 +48,7 @@ pub async fn fetch(config: FetchConfig) -> Result<String> {
     let temp_file_path = dest.join(&full_name);
     let mut temp_file = File::create(&temp_file_path).await?;
     let mut stream = response.bytes_stream();
+    let mut hasher = Sha256::new();
 
     while let Some(chunk) = match cancel_signal.as_mut() {
         Some(cancel_receiver) => {
@@ -63,6 +66,7 @@ pub async fn fetch(config: FetchConfig) -> Result<String> {
     } {
         let chunk = chunk?;
         downloaded_size += chunk.len();
+        hasher.update(&chunk);
         temp_file.write_all(&chunk).await?;
         (on_progress)("download", downloaded_size as usize, total_size as usize);
     }
@@ -130,10 +134,14 @@ pub async fn fetch(config: FetchConfig) -> Result<String> {
     }
 
     let (_rename_future, _remove_future) = tokio::join!(
-        rename(dest.join(&name), dest.join(&version)),
+        rename(dest.join(&name), dest.join(&target)),
         remove_file(temp_file_path)
     );
 
-    let path = dest.join(&version).to_string_lossy().to_string();
-    Ok(path)
+    let path = dest.join(&target).to_string_lossy().to_string();
+    Ok(FetchResult {
+        path,
+        archive: full_name,
+        checksum: format!("{:x}", hasher.finalize()),
+    })
 }
@@ -0,0 +1,92 @@
+use anyhow::{bail, Result};
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, fs::File, io, path::Path, time::Duration};
+
+use super::{archive::create_client, Proxy};
+
+pub struct ShasumsConfig {
+    /// fetch mirror url
+    pub mirror: String,
+
+    /// node version
+    pub version: String,
+
+    /// disable proxy
+    pub no_proxy: Option<bool>,
+
+    /// proxy ip & port
+    pub proxy: Option<Proxy>,
+
+    /// timeout
+    pub timeout: Option<Duration>,
+}
+
+/// fetch the `SHASUMS256.txt` of the version from the mirror
+/// return the map of file name to sha256 checksum
+pub async fn shasums(config: ShasumsConfig) -> Result<HashMap<String, String>> {
+    let ShasumsConfig {
+        mirror,
+        version,
+        no_proxy,
+        proxy,
+        timeout,
+    } = config;
+
+    // timeout default value is `20s`
+    let timeout = timeout.unwrap_or(Duration::from_millis(20000));
+    let client = create_client(proxy, no_proxy, timeout)?;
+    let url = format!(
+        "{}/v{}/SHASUMS256.txt",
+        mirror.trim_end_matches("/"),
+        version
+    );
+
+    let response = client.get(url).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!(format!("HTTP failure ({status})"));
+    }
+
+    Ok(parse_shasums(&response.text().await?))
+}
+
+/// parse the content of `SHASUMS256.txt`
+/// each line is `{checksum}  {file name}`
+pub fn parse_shasums(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(checksum), Some(name)) => Some((name.to_string(), checksum.to_string())),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// calculate the sha256 checksum of the file
+pub fn file_checksum(path: &Path) -> Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shasums() {
+        let shasums = parse_shasums(
+            "1a2b  node-v20.2.3-darwin-arm64.tar.gz\n3c4d  node-v20.2.3-win-x64.zip\n\n",
+        );
+
+        assert_eq!(shasums.len(), 2);
+        assert_eq!(
+            shasums.get("node-v20.2.3-win-x64.zip"),
+            Some(&"3c4d".to_string())
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 pub mod archive;
+pub mod checksum;
 pub mod list;
+pub mod mirror;
 mod node;
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
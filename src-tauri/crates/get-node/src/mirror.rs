@@ -0,0 +1,136 @@
+use anyhow::{bail, Result};
+use futures_util::{future::join_all, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, time::Duration};
+use tokio::time::Instant;
+
+use super::{archive::create_client, Proxy};
+
+pub struct BenchmarkConfig {
+    /// candidate mirror urls
+    pub mirrors: Vec<String>,
+
+    /// disable proxy
+    pub no_proxy: Option<bool>,
+
+    /// proxy ip & port
+    pub proxy: Option<Proxy>,
+
+    /// timeout for each mirror
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MirrorBenchmark {
+    /// mirror url
+    pub mirror: String,
+
+    /// time to the response of `index.json` (ms)
+    pub latency: Option<u64>,
+
+    /// download throughput of `index.json` (bytes/s)
+    pub throughput: Option<u64>,
+
+    /// total time to fetch `index.json` (ms)
+    pub elapsed: Option<u64>,
+
+    /// error message when the mirror is unavailable
+    pub error: Option<String>,
+}
+
+/// probe the `index.json` of every mirror concurrently
+/// return the results ranked from fastest to slowest
+pub async fn benchmark(config: BenchmarkConfig) -> Result<Vec<MirrorBenchmark>> {
+    let BenchmarkConfig {
+        mirrors,
+        no_proxy,
+        proxy,
+        timeout,
+    } = config;
+
+    // timeout default value is `10s`
+    let timeout = timeout.unwrap_or(Duration::from_millis(10000));
+    let client = create_client(proxy, no_proxy, timeout)?;
+
+    let results = join_all(mirrors.into_iter().map(|mirror| {
+        let client = client.clone();
+        async move {
+            match probe(&client, &mirror).await {
+                Ok((latency, elapsed, size)) => MirrorBenchmark {
+                    latency: Some(latency),
+                    throughput: Some(size * 1000 / elapsed.max(1)),
+                    elapsed: Some(elapsed),
+                    mirror,
+                    error: None,
+                },
+                Err(err) => MirrorBenchmark {
+                    mirror,
+                    error: Some(err.to_string()),
+                    ..MirrorBenchmark::default()
+                },
+            }
+        }
+    }))
+    .await;
+
+    Ok(rank(results))
+}
+
+/// return (latency, elapsed, size)
+async fn probe(client: &reqwest::Client, mirror: &str) -> Result<(u64, u64, u64)> {
+    let url = format!("{}/index.json", mirror.trim_end_matches("/"));
+    let start = Instant::now();
+
+    let response = client.get(url).send().await?;
+    let latency = start.elapsed().as_millis() as u64;
+
+    let status = response.status();
+    if !status.is_success() {
+        bail!(format!("HTTP failure ({status})"));
+    }
+
+    let mut size = 0;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        size += chunk?.len() as u64;
+    }
+    let elapsed = start.elapsed().as_millis() as u64;
+
+    Ok((latency, elapsed, size))
+}
+
+/// the available mirrors first, sorted by the total time
+fn rank(mut results: Vec<MirrorBenchmark>) -> Vec<MirrorBenchmark> {
+    results.sort_by(|a, b| match (a.elapsed, b.elapsed) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank() {
+        let result = |mirror: &str, elapsed: Option<u64>| MirrorBenchmark {
+            mirror: mirror.to_string(),
+            elapsed,
+            ..MirrorBenchmark::default()
+        };
+        let ranked = rank(vec![
+            result("a", None),
+            result("b", Some(300)),
+            result("c", Some(100)),
+        ]);
+
+        assert_eq!(
+            ranked.iter().map(|r| r.mirror.as_str()).collect::<Vec<_>>(),
+            vec!["c", "b", "a"]
+        );
+    }
+}
@@ -1,11 +1,15 @@
 use anyhow::Result;
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use crate::{
-    config::{Config, Group, ISettings, NVersion, Project},
-    core::{configration, group, handle, node, project},
+    config::{Config, Group, IAliases, ISettings, NVersion, Project},
+    core::{
+        alias, configration, discovery, group, handle, health, integrity, launcher, manifest,
+        mirror, node, packages, project, prune, required, runner, scanner, security, usage,
+        version_file, watcher,
+    },
     ret_err,
-    utils::dirs,
+    utils::{dirs, help},
     wrap_err,
 };
 
@@ -35,6 +39,18 @@ pub async fn installed_list(fetch: Option<bool>) -> CmdResult<Option<Vec<String>
     wrap_err!(node::get_installed_list(fetch).await)
 }
 
+/// get the security alerts of the versions in use
+#[tauri::command]
+pub async fn security_report() -> CmdResult<Vec<security::SecurityAlert>> {
+    wrap_err!(security::security_report().await)
+}
+
+/// read node installed versions with metadata
+#[tauri::command]
+pub async fn installed_versions(fetch: Option<bool>) -> CmdResult<Vec<manifest::InstalledVersion>> {
+    wrap_err!(manifest::installed_versions(fetch).await)
+}
+
 /// read settings
 #[tauri::command]
 pub async fn read_settings() -> CmdResult<ISettings> {
@@ -46,14 +62,37 @@ pub async fn read_settings() -> CmdResult<ISettings> {
 pub async fn update_settings(settings: ISettings) -> CmdResult<()> {
     let locale = Config::settings().latest().get_locale();
     let directory = Config::settings().latest().get_directory();
+    let sources = Config::settings().latest().get_sources();
 
     wrap_err!({ Config::settings().draft().patch_settings(settings.clone()) })?;
     Config::settings().apply();
 
-    // refresh data when directory changes
-    if directory != settings.directory {
+    let directory_changed = directory != settings.directory;
+    let sources_changed = settings.sources.is_some() && sources != settings.sources;
+    if let Some(directory) = &directory {
+        let unlinked = if directory_changed {
+            // the old directory is no longer used, none of its links are needed
+            sources.unwrap_or_default()
+        } else if sources_changed {
+            // the versions of the removed sources are no longer read-only, unlink them
+            sources
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|source| !settings.sources.iter().flatten().any(|item| item == source))
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+        wrap_err!(help::unlink_sources(directory, &unlinked))?;
+    }
+    // refresh data, the sources are linked into the current directory
+    if directory_changed || sources_changed {
         wrap_err!(node::get_installed_list(Some(true)).await)?;
     }
+    // watch the new directory when directory changes
+    if directory_changed {
+        wrap_err!(watcher::rewatch())?;
+    }
     // update system tray
     if locale != settings.locale || directory != settings.directory {
         wrap_err!(handle::Handle::update_systray_part())?;
@@ -62,14 +101,24 @@ pub async fn update_settings(settings: ISettings) -> CmdResult<()> {
     Ok(())
 }
 
+/// probe the mirrors & rank them by speed
+#[tauri::command]
+pub async fn benchmark_mirrors(
+    mirrors: Option<Vec<String>>,
+    apply: Option<bool>,
+) -> CmdResult<Vec<get_node::mirror::MirrorBenchmark>> {
+    wrap_err!(mirror::benchmark_mirrors(mirrors, apply).await)
+}
+
 /// install node
 #[tauri::command]
 pub async fn install_node(
     window: tauri::Window,
     version: Option<String>,
     arch: Option<String>,
+    migrate_from: Option<String>,
 ) -> CmdResult<String> {
-    wrap_err!(node::install_node(window, version, arch).await)
+    wrap_err!(node::install_node(window, version, arch, migrate_from).await)
 }
 
 /// install node
@@ -78,15 +127,103 @@ pub async fn install_node_cancel() -> CmdResult<()> {
     wrap_err!(node::install_node_cancel().await)
 }
 
+/// get the versions used by the default, projects, groups & aliases but not installed
+#[tauri::command]
+pub async fn missing_versions() -> CmdResult<Vec<required::MissingVersion>> {
+    Ok(required::missing_versions())
+}
+
+/// install the missing versions
+#[tauri::command]
+pub async fn install_missing(
+    window: tauri::Window,
+    names: Option<Vec<String>>,
+) -> CmdResult<Vec<required::RequiredInstall>> {
+    wrap_err!(required::install_missing(window, names).await)
+}
+
 /// uninstall node
 #[tauri::command]
-pub async fn uninstall_node(version: Option<String>, current: Option<bool>) -> CmdResult<()> {
+pub async fn uninstall_node(
+    version: Option<String>,
+    current: Option<bool>,
+    force: Option<bool>,
+    reassign: Option<String>,
+) -> CmdResult<Vec<usage::Usage>> {
     if version.is_none() {
         ret_err!("version should not be null");
     }
 
     let version = version.unwrap();
-    wrap_err!(node::uninstall_node(version, current).await)
+    wrap_err!(node::uninstall_node(version, current, force, reassign).await)
+}
+
+/// get the dependents of the version
+#[tauri::command]
+pub fn version_usages(version: String) -> CmdResult<Vec<usage::Usage>> {
+    Ok(usage::version_usages(&version))
+}
+
+/// reinstall the global packages of a version into another version
+#[tauri::command]
+pub async fn migrate_packages(
+    from: String,
+    to: String,
+    packages: Option<Vec<String>>,
+) -> CmdResult<Vec<packages::PackageResult>> {
+    wrap_err!(packages::migrate_packages(from, to, packages).await)
+}
+
+/// get the global packages of the version
+#[tauri::command]
+pub async fn global_packages(version: String) -> CmdResult<Vec<packages::GlobalPackage>> {
+    wrap_err!(packages::global_packages(version).await)
+}
+
+/// uninstall the global package of the version
+#[tauri::command]
+pub async fn uninstall_package(version: String, name: String) -> CmdResult<()> {
+    wrap_err!(packages::uninstall_package(version, name).await)
+}
+
+/// update the global package of the version
+#[tauri::command]
+pub async fn update_package(version: String, name: String, range: Option<String>) -> CmdResult<()> {
+    wrap_err!(packages::update_package(version, name, range).await)
+}
+
+/// remove the unused versions & the versions beyond the retention policy
+#[tauri::command]
+pub async fn prune_versions(options: prune::PruneOptions) -> CmdResult<prune::PruneReport> {
+    wrap_err!(prune::prune_versions(options).await)
+}
+
+/// verify the integrity of installed versions
+#[tauri::command]
+pub async fn verify_installed() -> CmdResult<Vec<integrity::VersionIntegrity>> {
+    wrap_err!(integrity::verify_installed().await)
+}
+
+/// reinstall node
+#[tauri::command]
+pub async fn reinstall_node(window: tauri::Window, version: String) -> CmdResult<String> {
+    wrap_err!(integrity::reinstall_node(window, version).await)
+}
+
+/// find node installations of other version managers
+#[tauri::command]
+pub async fn discover_versions() -> CmdResult<Vec<discovery::ExternalVersion>> {
+    wrap_err!(discovery::discover_versions().await)
+}
+
+/// import node installations of other version managers
+#[tauri::command]
+pub async fn import_versions(
+    list: Vec<discovery::ExternalVersion>,
+    mode: discovery::ImportMode,
+    set_default: Option<bool>,
+) -> CmdResult<Vec<discovery::ImportResult>> {
+    wrap_err!(discovery::import_versions(list, mode, set_default).await)
 }
 
 /// get project list
@@ -103,12 +240,66 @@ pub async fn select_projects(
     wrap_err!(project::select_projects(app_handle).await)
 }
 
+/// scan the directory for projects
+#[tauri::command]
+pub async fn scan_projects(
+    app_handle: tauri::AppHandle,
+    root: Option<PathBuf>,
+    depth: Option<usize>,
+) -> CmdResult<Option<Vec<project::PInfo>>> {
+    wrap_err!(scanner::scan_projects(app_handle, root, depth).await)
+}
+
+/// import the scanned projects
+#[tauri::command]
+pub async fn import_projects(list: Vec<project::PInfo>) -> CmdResult<Vec<Project>> {
+    wrap_err!(scanner::import_projects(list).await)
+}
+
+/// detect the node version of the project from its version files
+#[tauri::command]
+pub async fn detect_project_version(
+    path: PathBuf,
+) -> CmdResult<Option<version_file::DetectedVersion>> {
+    wrap_err!(version_file::detect_version(&path).await)
+}
+
+/// check the projects for missing paths, uninstalled versions, missing groups & drifted version files
+#[tauri::command]
+pub async fn project_health() -> CmdResult<Vec<health::HealthIssue>> {
+    wrap_err!(health::project_health().await)
+}
+
+/// fix the project issues of the kinds, return the issues left
+#[tauri::command]
+pub async fn fix_project_health(
+    kinds: Vec<health::HealthIssueKind>,
+) -> CmdResult<Vec<health::HealthIssue>> {
+    wrap_err!(health::fix_project_health(kinds).await)
+}
+
 /// update projects
 #[tauri::command]
 pub async fn update_projects(list: Vec<Project>, path: Option<PathBuf>) -> CmdResult<()> {
     wrap_err!(project::update_projects(list, path).await)
 }
 
+/// update the version files maintained for the project
+#[tauri::command]
+pub async fn update_project_version_files(id: String, files: Option<Vec<String>>) -> CmdResult<()> {
+    wrap_err!(project::update_project_version_files(id, files).await)
+}
+
+/// update the environment variables of the project
+#[tauri::command]
+pub async fn update_project_env(
+    id: String,
+    env: BTreeMap<String, String>,
+    env_file: bool,
+) -> CmdResult<()> {
+    wrap_err!(project::update_project_env(id, env, env_file).await)
+}
+
 /// update project version
 #[tauri::command]
 pub async fn sync_project_version(path: PathBuf, version: String) -> CmdResult<i32> {
@@ -117,8 +308,54 @@ pub async fn sync_project_version(path: PathBuf, version: String) -> CmdResult<i
 
 /// batch update project version
 #[tauri::command]
-pub async fn batch_update_project_version(paths: Vec<PathBuf>, version: String) -> CmdResult<()> {
-    wrap_err!(project::batch_update_project_version(paths, version).await)
+pub async fn batch_update_project_version(
+    paths: Vec<PathBuf>,
+    version: String,
+    record: Option<String>,
+) -> CmdResult<project::BatchUpdate> {
+    wrap_err!(project::batch_update_project_version(paths, version, record).await)
+}
+
+/// get the scripts of the project
+#[tauri::command]
+pub async fn project_scripts(path: PathBuf) -> CmdResult<Vec<runner::Script>> {
+    wrap_err!(runner::project_scripts(path).await)
+}
+
+/// run the npm script of the project with the project's node version
+#[tauri::command]
+pub async fn run_script(path: PathBuf, script: String) -> CmdResult<runner::ProcessInfo> {
+    wrap_err!(runner::run_script(path, script).await)
+}
+
+/// stop the running process
+#[tauri::command]
+pub async fn stop_process(id: String) -> CmdResult<()> {
+    wrap_err!(runner::stop_process(id).await)
+}
+
+/// restart the process
+#[tauri::command]
+pub async fn restart_process(id: String) -> CmdResult<runner::ProcessInfo> {
+    wrap_err!(runner::restart_process(id).await)
+}
+
+/// remove the finished process
+#[tauri::command]
+pub fn remove_process(id: String) -> CmdResult<()> {
+    wrap_err!(runner::remove_process(id))
+}
+
+/// get the processes
+#[tauri::command]
+pub fn process_list(path: Option<PathBuf>) -> CmdResult<Vec<runner::ProcessInfo>> {
+    Ok(runner::process_list(path))
+}
+
+/// get the last output lines of the process
+#[tauri::command]
+pub fn process_output(id: String) -> CmdResult<Vec<runner::OutputLine>> {
+    wrap_err!(runner::process_output(id))
 }
 
 /// get group list
@@ -135,10 +372,31 @@ pub async fn update_groups(list: Vec<Group>) -> CmdResult<()> {
 
 /// update group version
 #[tauri::command]
-pub async fn update_group_version(name: String, version: String) -> CmdResult<()> {
+pub async fn update_group_version(
+    name: String,
+    version: String,
+) -> CmdResult<project::BatchUpdate> {
     wrap_err!(group::update_group_version(name, version).await)
 }
 
+/// get aliases
+#[tauri::command]
+pub async fn alias_list(fetch: Option<bool>) -> CmdResult<IAliases> {
+    wrap_err!(alias::alias_list(fetch).await)
+}
+
+/// create or repoint alias
+#[tauri::command]
+pub async fn set_alias(name: String, version: String) -> CmdResult<()> {
+    wrap_err!(alias::set_alias(name, version).await)
+}
+
+/// remove alias
+#[tauri::command]
+pub async fn remove_alias(name: String) -> CmdResult<()> {
+    wrap_err!(alias::remove_alias(name).await)
+}
+
 /// configration export
 #[tauri::command]
 pub async fn configration_export(
@@ -177,6 +435,18 @@ pub fn open_dir(dir: String) -> CmdResult<()> {
     wrap_err!(open::that(dir))
 }
 
+/// open the terminal at the project with the project's node version
+#[tauri::command]
+pub async fn open_terminal(path: PathBuf) -> CmdResult<()> {
+    wrap_err!(launcher::open_terminal(path).await)
+}
+
+/// open the editor at the project with the project's node version
+#[tauri::command]
+pub async fn open_editor(path: PathBuf) -> CmdResult<()> {
+    wrap_err!(launcher::open_editor(path).await)
+}
+
 /// restart app
 #[tauri::command]
 pub fn restart(app_handle: tauri::AppHandle) {
@@ -186,6 +456,7 @@ pub fn restart(app_handle: tauri::AppHandle) {
 /// exit app
 #[tauri::command]
 pub fn exit_app(app_handle: tauri::AppHandle) {
+    tauri::async_runtime::block_on(runner::stop_all());
     app_handle.exit(0);
     std::process::exit(0);
 }
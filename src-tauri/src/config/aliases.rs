@@ -0,0 +1,94 @@
+use crate::utils::{dirs, help};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Alias {
+    /// alias name
+    pub name: String,
+
+    /// the node version pointed to
+    /// a full version (`18.19.0`) or a major/minor prefix (`14`, `18.19`)
+    pub version: String,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct IAliases {
+    /// aliases list
+    pub list: Option<Vec<Alias>>,
+
+    /// the alias followed by the global version
+    pub default: Option<String>,
+}
+
+impl IAliases {
+    pub fn new() -> Self {
+        match dirs::aliases_path().and_then(|path| help::read_json::<IAliases>(&path)) {
+            Ok(aliases) => aliases,
+            Err(err) => {
+                log::error!(target: "app", "{err}");
+                Self::template()
+            }
+        }
+    }
+
+    /// return the default data
+    pub fn template() -> Self {
+        Self {
+            list: Some(vec![]),
+            default: None,
+        }
+    }
+
+    /// save aliases to local file
+    pub fn save_file(&self) -> Result<()> {
+        help::save_json(&dirs::aliases_path()?, &self, None)
+    }
+
+    /// get list
+    pub fn get_list(&self) -> Option<Vec<Alias>> {
+        self.list.clone()
+    }
+
+    /// get the version pointed to by the alias
+    pub fn get_version(&self, name: &str) -> Option<String> {
+        self.list
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .find(|alias| alias.name == name)
+            .map(|alias| alias.version.clone())
+    }
+
+    /// create or repoint the alias
+    pub fn update_alias(&mut self, name: &str, version: &str) -> Result<()> {
+        let list = self.list.get_or_insert_with(Vec::new);
+        match list.iter_mut().find(|alias| alias.name == name) {
+            Some(alias) => alias.version = version.to_string(),
+            None => list.push(Alias {
+                name: name.to_string(),
+                version: version.to_string(),
+            }),
+        }
+        Ok(())
+    }
+
+    /// remove the alias
+    pub fn remove_alias(&mut self, name: &str) -> Result<()> {
+        let list = self.list.get_or_insert_with(Vec::new);
+        if !list.iter().any(|alias| alias.name == name) {
+            bail!("failed to find the alias item \"name:{name}\"");
+        }
+        list.retain(|alias| alias.name != name);
+        if self.default.as_deref() == Some(name) {
+            self.default = None;
+        }
+        Ok(())
+    }
+
+    /// update the alias followed by the global version
+    pub fn update_default(&mut self, name: Option<String>) -> Result<()> {
+        self.default = name;
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
-use super::{Draft, IGroups, INode, IProjects, ISettings};
+use super::{Draft, IAliases, IGroups, INode, IProjects, ISettings};
 
 use once_cell::sync::OnceCell;
 
 pub struct Config {
+    alias_config: Draft<IAliases>,
     node_config: Draft<INode>,
     group_config: Draft<IGroups>,
     project_config: Draft<IProjects>,
@@ -16,15 +17,29 @@ impl Config {
         CONFIG.get_or_init(|| {
             let setting_config = Draft::from(ISettings::new());
             let directory = setting_config.data().directory.clone();
+            let sources = setting_config.data().sources.clone();
+            let projects = IProjects::new();
+            let mut groups = IGroups::new();
+            // the groups saved before the project ids were introduced
+            if groups.migrate_members(projects.list.as_deref().unwrap_or_default()) {
+                if let Err(err) = groups.save_file() {
+                    log::error!(target: "app", "{err}");
+                }
+            }
             Config {
-                node_config: Draft::from(INode::new(directory)),
-                group_config: Draft::from(IGroups::new()),
-                project_config: Draft::from(IProjects::new()),
+                alias_config: Draft::from(IAliases::new()),
+                node_config: Draft::from(INode::new(directory, sources)),
+                group_config: Draft::from(groups),
+                project_config: Draft::from(projects),
                 setting_config,
             }
         })
     }
 
+    pub fn aliases() -> Draft<IAliases> {
+        Self::global().alias_config.clone()
+    }
+
     pub fn node() -> Draft<INode> {
         Self::global().node_config.clone()
     }
@@ -1,4 +1,4 @@
-use super::{IGroups, INode, IProjects, ISettings};
+use super::{IAliases, IGroups, INode, IProjects, ISettings};
 use parking_lot::{MappedMutexGuard, Mutex, MutexGuard};
 use std::sync::Arc;
 
@@ -64,6 +64,7 @@ macro_rules! draft_define {
     };
 }
 
+draft_define!(IAliases);
 draft_define!(IGroups);
 draft_define!(INode);
 draft_define!(IProjects);
@@ -1,3 +1,4 @@
+use super::Project;
 use crate::utils::{dirs, help};
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
@@ -10,7 +11,7 @@ pub struct Group {
     /// group desc
     pub desc: Option<String>,
 
-    /// the group contains projects
+    /// the ids of the projects in the group
     #[serde(default = "default_projects")]
     pub projects: Vec<String>,
 
@@ -54,6 +55,34 @@ impl IGroups {
         self.list.clone()
     }
 
+    /// replace the member paths saved before the project ids were introduced with the ids
+    /// the members matching no project are kept, return whether any member changed
+    pub fn migrate_members(&mut self, projects: &[Project]) -> bool {
+        let mut changed = false;
+        for group in self.list.iter_mut().flatten() {
+            let members = group
+                .projects
+                .iter()
+                .map(|member| {
+                    projects
+                        .iter()
+                        .find(|project| &project.path == member && !project.id.is_empty())
+                        .map_or_else(|| member.clone(), |project| project.id.clone())
+                })
+                .fold(vec![], |mut members, id| {
+                    if !members.contains(&id) {
+                        members.push(id);
+                    }
+                    members
+                });
+            if members != group.projects {
+                group.projects = members;
+                changed = true;
+            }
+        }
+        changed
+    }
+
     /// update groups list
     pub fn update_list(&mut self, list: &Vec<Group>) -> Result<()> {
         self.list = Some(list.clone());
@@ -79,12 +108,13 @@ impl IGroups {
     }
 
     /// update the projects of group for system tray menu
-    pub fn update_projects(&mut self, path: &String) -> Result<bool> {
+    /// id: the project id
+    pub fn update_projects(&mut self, id: &String) -> Result<bool> {
         let mut list = self.list.take().unwrap_or_default();
 
         for each in list.iter_mut() {
-            if each.projects.contains(path) {
-                each.projects.retain(|p| p != path);
+            if each.projects.contains(id) {
+                each.projects.retain(|p| p != id);
 
                 self.list = Some(list);
                 return Ok(true);
@@ -98,18 +128,19 @@ impl IGroups {
     /// update the projects of group for system tray menu
     /// remove from old group
     /// add to new group
-    pub fn update_projects_version(&mut self, path: &str, name: &str) -> Result<Option<String>> {
+    /// id: the project id
+    pub fn update_projects_version(&mut self, id: &str, name: &str) -> Result<Option<String>> {
         let mut list = self.list.take().unwrap_or_default();
         let mut version: Option<String> = None;
 
         for each in list.iter_mut() {
-            if each.projects.contains(&path.to_string()) {
-                each.projects.retain(|p| p != path);
+            if each.projects.contains(&id.to_string()) {
+                each.projects.retain(|p| p != id);
             }
 
             if &each.name == name {
                 version = each.version.clone();
-                each.projects.push(path.to_string());
+                each.projects.push(id.to_string());
             }
         }
 
@@ -117,3 +148,29 @@ impl IGroups {
         Ok(version)
     }
 }
+
+#[test]
+fn test_migrate_members() {
+    let project = |id: &str, path: &str| Project {
+        id: id.to_string(),
+        path: path.to_string(),
+        ..Project::default()
+    };
+    let projects = vec![project("a", "/work/app"), project("b", "/work/lib")];
+    let mut groups = IGroups {
+        list: Some(vec![Group {
+            name: "frontend".to_string(),
+            projects: ["/work/app", "b", "/work/lib", "/work/gone"]
+                .map(String::from)
+                .to_vec(),
+            ..Group::default()
+        }]),
+    };
+
+    assert!(groups.migrate_members(&projects));
+    assert_eq!(
+        groups.list.as_ref().unwrap()[0].projects,
+        vec!["a", "b", "/work/gone"]
+    );
+    assert!(!groups.migrate_members(&projects));
+}
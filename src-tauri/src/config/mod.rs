@@ -1,11 +1,13 @@
 #[allow(clippy::module_inception)]
 mod config;
+mod aliases;
 mod draft;
 mod groups;
 mod node;
 mod projects;
 mod settings;
 
+pub use self::aliases::*;
 pub use self::config::*;
 pub use self::draft::*;
 pub use self::groups::*;
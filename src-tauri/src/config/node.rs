@@ -1,11 +1,8 @@
-use std::cmp::Ordering;
-
 use crate::utils::{dirs, help};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use version_compare::{compare, Cmp};
 
 #[derive(Default, Debug, Clone, Deserialize, Serialize)]
 pub struct NVersion {
@@ -30,6 +27,9 @@ pub struct NVersion {
 
     /// the downloadbable files with types
     pub files: Vec<String>,
+
+    /// whether the version is a security release
+    pub security: Option<bool>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize, Serialize)]
@@ -42,10 +42,16 @@ pub struct INode {
 
     /// installed node versions
     pub installed: Option<Vec<String>>,
+
+    /// the installed versions from read-only sources
+    pub readonly: Option<Vec<String>>,
+
+    /// the installed versions registered from other version managers
+    pub external: Option<Vec<String>>,
 }
 
 impl INode {
-    pub fn new(directory: Option<String>) -> Self {
+    pub fn new(directory: Option<String>, sources: Option<Vec<String>>) -> Self {
         // get current version from `default`
         let current = dirs::default_version_path()
             .and_then(|path| help::read_string(&path))
@@ -63,6 +69,14 @@ impl INode {
                 Some(vec![])
             });
 
+        let readonly = directory
+            .as_ref()
+            .map(|path| help::linked_sources(path, sources.as_deref().unwrap_or(&[])))
+            .unwrap_or_default();
+        let external = directory
+            .as_ref()
+            .map(|path| help::external_versions(path, &readonly))
+            .unwrap_or_default();
         let mut installed = directory
             .map(|path| {
                 help::read_installed(&path).unwrap_or_else(|err| {
@@ -71,17 +85,14 @@ impl INode {
                 })
             })
             .unwrap_or(vec![]);
-        installed.sort_by(|a, b| match compare(b, a) {
-            Ok(Cmp::Lt) => Ordering::Less,
-            Ok(Cmp::Eq) => Ordering::Equal,
-            Ok(Cmp::Gt) => Ordering::Greater,
-            _ => unreachable!(),
-        });
+        installed.sort_by(|a, b| help::compare_installed(a, b));
 
         Self {
             current,
             list,
             installed: Some(installed),
+            readonly: Some(readonly),
+            external: Some(external),
         }
     }
 
@@ -90,6 +101,8 @@ impl INode {
         Self {
             list: Some(vec![]),
             installed: Some(vec![]),
+            readonly: Some(vec![]),
+            external: Some(vec![]),
             ..Self::default()
         }
     }
@@ -133,6 +146,16 @@ impl INode {
         self.installed.clone()
     }
 
+    /// get the installed versions from read-only sources
+    pub fn get_readonly(&self) -> Option<Vec<String>> {
+        self.readonly.clone()
+    }
+
+    /// get the installed versions registered from other version managers
+    pub fn get_external(&self) -> Option<Vec<String>> {
+        self.external.clone()
+    }
+
     /// update current
     pub fn update_current(&mut self, current: &str) -> Result<()> {
         self.current = Some(current.to_string());
@@ -150,4 +173,16 @@ impl INode {
         self.installed = Some(installed.clone());
         Ok(())
     }
+
+    /// update the installed versions from read-only sources
+    pub fn update_readonly(&mut self, readonly: &[String]) -> Result<()> {
+        self.readonly = Some(readonly.to_vec());
+        Ok(())
+    }
+
+    /// update the installed versions registered from other version managers
+    pub fn update_external(&mut self, external: &[String]) -> Result<()> {
+        self.external = Some(external.to_vec());
+        Ok(())
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use crate::utils::{dirs, help};
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
@@ -5,6 +7,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Project {
+    /// the stable unique id, assigned when the project is saved
+    #[serde(default)]
+    pub id: String,
+
     /// is it active
     pub active: bool,
 
@@ -17,6 +23,17 @@ pub struct Project {
     /// the node version of project used
     pub version: Option<String>,
 
+    /// the version files kept up to date besides `.nvmdrc`, the global setting when `None`
+    /// `.nvmrc`, `.node-version` or `package.json#volta`
+    pub version_files: Option<Vec<String>>,
+
+    /// the environment variables applied when running commands or opening terminals for the project
+    /// e.g. `NODE_OPTIONS`, `NODE_EXTRA_CA_CERTS`
+    pub env: Option<BTreeMap<String, String>>,
+
+    /// mirror the environment variables into `.nvmdenv` of the project for the shim
+    pub env_file: Option<bool>,
+
     /// create date
     pub create_at: Option<String>,
 
@@ -30,12 +47,45 @@ pub struct IProjects {
     pub list: Option<Vec<Project>>,
 }
 
+/// generate a new project id
+fn new_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// assign the ids to the projects without one (or with a duplicated one)
+/// the id of the previous project with the same path is reused
+/// return whether any id was assigned
+fn assign_ids(list: &mut [Project], previous: &[Project]) -> bool {
+    let mut assigned = false;
+    let mut seen: Vec<String> = vec![];
+    for project in list.iter_mut() {
+        if project.id.is_empty() || seen.contains(&project.id) {
+            project.id = previous
+                .iter()
+                .find(|item| item.path == project.path && !item.id.is_empty())
+                .map(|item| item.id.clone())
+                .filter(|id| !seen.contains(id))
+                .unwrap_or_else(new_id);
+            assigned = true;
+        }
+        seen.push(project.id.clone());
+    }
+    assigned
+}
+
 impl IProjects {
     pub fn new() -> Self {
         match dirs::projects_path().and_then(|path| help::read_json::<Vec<Project>>(&path)) {
-            Ok(projects) => Self {
-                list: Some(projects),
-            },
+            Ok(mut projects) => {
+                // the projects saved before the ids were introduced
+                if assign_ids(&mut projects, &[]) {
+                    let _ = dirs::projects_path()
+                        .and_then(|path| help::save_json(&path, &projects, None));
+                }
+                Self {
+                    list: Some(projects),
+                }
+            }
             Err(err) => {
                 log::error!(target: "app", "{err}");
                 Self::template()
@@ -59,17 +109,35 @@ impl IProjects {
     }
 
     /// update project list
+    /// the projects added without an id get one
     pub fn update_list(&mut self, list: &Vec<Project>) -> Result<()> {
-        self.list = Some(list.clone());
+        let previous = self.list.take().unwrap_or_default();
+        let mut list = list.clone();
+        assign_ids(&mut list, &previous);
+        self.list = Some(list);
         Ok(())
     }
 
-    /// update project version for system tray menu
-    pub fn update_version(&mut self, name: &str, version: &str) -> Result<String> {
+    /// update the version of the projects by their paths, e.g. the members of a group
+    /// an empty version unsets the project version
+    pub fn update_versions(&mut self, paths: &[String], version: &str) -> Result<()> {
+        let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        for each in self.list.iter_mut().flatten() {
+            if paths.contains(&each.path) {
+                each.version = Some(version.to_string()).filter(|version| !version.is_empty());
+                each.update_at = Some(now.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// update project version by the project id
+    /// return the project path
+    pub fn update_version(&mut self, id: &str, version: &str) -> Result<String> {
         let mut list = self.list.take().unwrap_or_default();
 
         for each in list.iter_mut() {
-            if each.name == name {
+            if each.id == id {
                 each.version = Some(version.to_string());
                 let path = each.path.clone();
                 self.list = Some(list);
@@ -78,6 +146,61 @@ impl IProjects {
         }
 
         self.list = Some(list);
-        bail!("failed to find the project item \"name:{name}\"");
+        bail!("failed to find the project item \"id:{id}\"");
+    }
+
+    /// update the version files maintained for the project by the project id
+    /// return the project
+    pub fn update_version_files(
+        &mut self,
+        id: &str,
+        files: Option<Vec<String>>,
+    ) -> Result<Project> {
+        let Some(project) = self.list.iter_mut().flatten().find(|each| each.id == id) else {
+            bail!("failed to find the project item \"id:{id}\"");
+        };
+        project.version_files = files;
+        Ok(project.clone())
+    }
+
+    /// update the environment variables of the project by the project id
+    /// return the project path
+    pub fn update_env(
+        &mut self,
+        id: &str,
+        env: BTreeMap<String, String>,
+        env_file: bool,
+    ) -> Result<String> {
+        let Some(project) = self.list.iter_mut().flatten().find(|each| each.id == id) else {
+            bail!("failed to find the project item \"id:{id}\"");
+        };
+        project.env = Some(env).filter(|env| !env.is_empty());
+        project.env_file = Some(env_file);
+        project.update_at =
+            Some(chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true));
+        Ok(project.path.clone())
     }
 }
+
+#[test]
+fn test_assign_ids() {
+    let project = |id: &str, path: &str| Project {
+        id: id.to_string(),
+        path: path.to_string(),
+        ..Project::default()
+    };
+    let previous = vec![project("a", "/work/app"), project("b", "/work/lib")];
+    let mut list = vec![
+        project("", "/work/new"),
+        project("", "/work/app"),
+        project("b", "/work/lib"),
+        project("b", "/other/lib"),
+    ];
+
+    assert!(assign_ids(&mut list, &previous));
+    assert!(!list[0].id.is_empty());
+    assert_eq!(list[1].id, "a");
+    assert_eq!(list[2].id, "b");
+    assert!(list[3].id != "b" && !list[3].id.is_empty());
+    assert!(!assign_ids(&mut list, &previous));
+}
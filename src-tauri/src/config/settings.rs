@@ -13,6 +13,27 @@ pub struct ISettings {
     /// installation directory
     pub directory: Option<String>,
 
+    /// read-only directories whose versions are listed alongside the installed ones
+    /// they are never modified or uninstalled
+    pub sources: Option<Vec<String>>,
+
+    /// the npm packages installed globally with every new version
+    /// e.g. `typescript` or `pnpm@^8`
+    pub default_packages: Option<Vec<String>>,
+
+    /// the version files kept up to date for projects besides `.nvmdrc`
+    /// `.nvmrc`, `.node-version` or `package.json#volta`
+    pub version_files: Option<Vec<String>>,
+
+    /// the command to open a terminal at a project, `{path}` is replaced by the project path
+    /// e.g. `wt -d {path}` or `kitty --directory {path}`
+    /// `open -a` launches through LaunchServices, the terminal does not get the project's node in `PATH`
+    pub terminal: Option<String>,
+
+    /// the command to open an editor at a project, `{path}` is replaced by the project path
+    /// e.g. `code {path}` or `webstorm {path}`
+    pub editor: Option<String>,
+
     /// not show the window on launch
     pub enable_silent_start: Option<bool>,
 
@@ -80,6 +101,26 @@ impl ISettings {
         self.directory.clone()
     }
 
+    /// get the value of `sources`
+    pub fn get_sources(&self) -> Option<Vec<String>> {
+        self.sources.clone()
+    }
+
+    /// get the value of `version_files`
+    pub fn get_version_files(&self) -> Option<Vec<String>> {
+        self.version_files.clone()
+    }
+
+    /// get the value of `terminal`
+    pub fn get_terminal(&self) -> Option<String> {
+        self.terminal.clone()
+    }
+
+    /// get the value of `editor`
+    pub fn get_editor(&self) -> Option<String> {
+        self.editor.clone()
+    }
+
     /// get the value of `mirror`
     pub fn get_mirror(&self) -> Option<String> {
         self.mirror.clone()
@@ -108,6 +149,11 @@ impl ISettings {
 
         patch!(closer);
         patch!(directory);
+        patch!(sources);
+        patch!(default_packages);
+        patch!(version_files);
+        patch!(terminal);
+        patch!(editor);
         patch!(enable_silent_start);
         patch!(locale);
         patch!(mirror);
@@ -0,0 +1,231 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{
+    config::{Config, IAliases},
+    log_err,
+    utils::{dirs, help},
+};
+
+use super::{handle, project};
+
+/// get the aliases from `aliases.json`
+pub async fn alias_list(fetch: Option<bool>) -> Result<IAliases> {
+    let fetch = fetch.unwrap_or(false);
+    if !fetch {
+        return Ok(Config::aliases().latest().clone());
+    }
+
+    let path = dirs::aliases_path()?;
+    let aliases = if path.exists() {
+        help::async_read_json::<IAliases>(&path).await?
+    } else {
+        IAliases::template()
+    };
+
+    // update aliases
+    *Config::aliases().draft() = aliases.clone();
+    Config::aliases().apply();
+
+    Ok(aliases)
+}
+
+/// return the alias name if the version is an alias
+pub fn find_alias(version: &str) -> Option<String> {
+    Config::aliases()
+        .latest()
+        .get_version(version)
+        .map(|_| version.to_string())
+}
+
+/// whether the version matches the major/minor prefix
+pub fn matches_prefix(version: &str, prefix: &str) -> bool {
+    version == prefix || version.starts_with(&format!("{prefix}."))
+}
+
+/// find the newest installed version (sorted from the newest) matching the prefix
+/// only the platform default arch, the other archs are chosen by their installation name
+fn newest_matching(installed: Vec<String>, prefix: &str) -> Option<String> {
+    installed.into_iter().find(|installed| {
+        help::split_install_name(installed).1.is_none() && matches_prefix(installed, prefix)
+    })
+}
+
+/// resolve the version if it is an alias, otherwise return it unchanged
+/// a prefix alias resolves to the newest installed version matching it
+pub fn resolve_version(version: &str) -> Result<String> {
+    let Some(target) = Config::aliases().latest().get_version(version) else {
+        return Ok(version.to_string());
+    };
+    if target.split('.').count() == 3 {
+        return Ok(target);
+    }
+
+    // the installed list is sorted from the newest
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+    newest_matching(installed, &target)
+        .ok_or_else(|| anyhow!("no installed version matches the alias \"{version}\" ({target})"))
+}
+
+/// check the alias name & target version
+/// return the normalized target version
+pub fn validate(name: &str, version: &str) -> Result<String> {
+    if name.is_empty() {
+        bail!("the alias name should not be empty");
+    }
+    let looks_like_version = name
+        .trim_start_matches('v')
+        .starts_with(|c: char| c.is_ascii_digit());
+    if looks_like_version {
+        bail!("the alias name \"{name}\" should not look like a version");
+    }
+    let groups = { Config::groups().latest().get_list() }.unwrap_or_default();
+    if groups.iter().any(|group| group.name == name) {
+        bail!("the alias name \"{name}\" is already used by a group");
+    }
+
+    let version = version.trim().trim_start_matches('v');
+    // an installation name of another arch (`{version}-{arch}`) needs the full version
+    let (plain, arch) = help::split_install_name(version);
+    let parts = plain.split('.').collect::<Vec<_>>();
+    let valid = parts.len() <= 3
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        && arch.is_none_or(|arch| parts.len() == 3 && !arch.is_empty());
+    if !valid {
+        bail!(
+            "the alias version \"{version}\" should be like `18.19.0`, `18.19`, `18` or `18.19.0-x64`"
+        );
+    }
+
+    Ok(version.to_string())
+}
+
+/// create or repoint the alias & update every consumer
+pub async fn set_alias(name: String, version: String) -> Result<()> {
+    let name = name.trim();
+    let version = validate(name, &version)?;
+
+    Config::aliases().draft().update_alias(name, &version)?;
+    Config::aliases().apply();
+    Config::aliases().data().save_file()?;
+
+    log_err!(sync_consumers(name).await);
+    log_err!(handle::Handle::update_systray_part_with_emit(
+        "call-projects-update",
+        ""
+    ));
+
+    Ok(())
+}
+
+/// remove the alias
+/// the alias is kept when it is still used by projects or groups
+pub async fn remove_alias(name: String) -> Result<()> {
+    let projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+    let groups = { Config::groups().latest().get_list() }.unwrap_or_default();
+
+    let mut users = projects
+        .iter()
+        .filter(|project| project.version.as_deref() == Some(&name))
+        .map(|project| project.name.clone())
+        .collect::<Vec<_>>();
+    users.extend(
+        groups
+            .iter()
+            .filter(|group| group.version.as_deref() == Some(&name))
+            .map(|group| group.name.clone()),
+    );
+    if !users.is_empty() {
+        bail!(
+            "the alias \"{name}\" is still used by: {}",
+            users.join(", ")
+        );
+    }
+
+    Config::aliases().draft().remove_alias(&name)?;
+    Config::aliases().apply();
+    Config::aliases().data().save_file()?;
+
+    log_err!(handle::Handle::update_systray_part());
+
+    Ok(())
+}
+
+/// remember the alias followed by the global version
+pub fn update_default(name: Option<String>) -> Result<()> {
+    if Config::aliases().latest().default == name {
+        return Ok(());
+    }
+
+    Config::aliases().draft().update_default(name)?;
+    Config::aliases().apply();
+    Config::aliases().data().save_file()
+}
+
+/// write the resolved version to the global `default` & the `.nvmdrc` of projects using the alias
+async fn sync_consumers(name: &str) -> Result<()> {
+    let version = resolve_version(name)?;
+
+    let aliases = Config::aliases().latest().clone();
+    if aliases.default.as_deref() == Some(name)
+        && Config::node().latest().get_current().as_deref() != Some(&version)
+    {
+        Config::node().draft().update_current(&version)?;
+        Config::node().apply();
+        Config::node().data().save_current()?;
+        log_err!(handle::Handle::emit_to_main(
+            "call-current-update",
+            &version
+        ));
+    }
+
+    // projects using the alias directly or through their group
+    let groups = { Config::groups().latest().get_list() }.unwrap_or_default();
+    let group_names = groups
+        .iter()
+        .filter(|group| group.version.as_deref() == Some(name))
+        .map(|group| group.name.as_str())
+        .collect::<Vec<_>>();
+    let projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+    for project in projects {
+        let Some(project_version) = project.version.as_deref() else {
+            continue;
+        };
+        if project_version == name || group_names.contains(&project_version) {
+            // a missing project should not stop the others
+            log_err!(project::sync_project_version(PathBuf::from(&project.path), &version).await);
+        }
+    }
+
+    Ok(())
+}
+
+/// re-resolve every alias, should be called when the installed versions change
+pub async fn sync_aliases() -> Result<()> {
+    let aliases = { Config::aliases().latest().get_list() }.unwrap_or_default();
+    for alias in aliases {
+        log_err!(sync_consumers(&alias.name).await);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_matches_prefix() {
+    assert!(matches_prefix("14.21.3", "14"));
+    assert!(matches_prefix("18.19.0", "18.19"));
+    assert!(!matches_prefix("140.0.0", "14"));
+    assert!(!matches_prefix("18.1.0", "18.19"));
+}
+
+#[test]
+fn test_newest_matching() {
+    let installed = ["18.19.0", "16.20.2-x64", "16.20.2", "16.14.0"].map(String::from);
+    assert_eq!(
+        newest_matching(installed.to_vec(), "16"),
+        Some("16.20.2".to_string())
+    );
+    assert_eq!(newest_matching(vec!["16.20.2-x64".to_string()], "16"), None);
+}
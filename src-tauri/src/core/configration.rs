@@ -1,5 +1,8 @@
-use super::{handle, project::sync_project_version};
-use crate::{config::{Config, Group, ISettings, Project}, log_err};
+use super::{alias, handle, project::sync_project_version};
+use crate::{
+    config::{Alias, Config, Group, IGroups, ISettings, Project},
+    log_err,
+};
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -37,6 +40,9 @@ pub struct ConfigrationData {
 
     /// export groups data
     groups: Option<Vec<Group>>,
+
+    /// export aliases data
+    aliases: Option<Vec<Alias>>,
 }
 
 #[derive(Default, Serialize)]
@@ -80,6 +86,7 @@ pub async fn configration_export(
     if projects.unwrap_or(false) {
         output.projects = Config::projects().latest().get_list();
         output.groups = Config::groups().latest().get_list();
+        output.aliases = Config::aliases().latest().get_list();
     }
 
     let output_json = serde_json::to_string_pretty(&output)?;
@@ -107,6 +114,34 @@ pub async fn configration_import(
         let configration: ConfigrationData = serde_json::from_str(&data)?;
         let projects = configration.projects.unwrap_or_default();
         let groups = configration.groups.unwrap_or_default();
+        let aliases = configration.aliases.unwrap_or_default();
+
+        // merge the aliases first, projects may use them
+        // the invalid aliases & the aliases named after the imported groups are dropped
+        let aliases = aliases
+            .into_iter()
+            .filter_map(|alias| {
+                if groups.iter().any(|group| group.name == alias.name) {
+                    let name = alias.name;
+                    log::error!(target: "app", "the alias name \"{name}\" is already used by a group");
+                    return None;
+                }
+                match alias::validate(&alias.name, &alias.version) {
+                    Ok(version) => Some((alias.name, version)),
+                    Err(err) => {
+                        log::error!(target: "app", "{err}");
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if !aliases.is_empty() {
+            for (name, version) in &aliases {
+                Config::aliases().draft().update_alias(name, version)?;
+            }
+            Config::aliases().apply();
+            Config::aliases().data().save_file()?;
+        }
 
         // need sync node version for every project
         if sync {
@@ -134,7 +169,12 @@ pub async fn configration_import(
             Config::projects().data().save_file()?;
         }
         // update groups data
+        // the groups exported before the project ids were introduced list the project paths
         if need_update_groups {
+            let mut groups = IGroups { list: Some(groups) };
+            let projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+            groups.migrate_members(&projects);
+            let groups = groups.list.unwrap_or_default();
             Config::groups().draft().update_list(&groups)?;
             Config::groups().apply();
             Config::groups().data().save_file()?;
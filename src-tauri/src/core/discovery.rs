@@ -0,0 +1,442 @@
+use std::{
+    cmp::Ordering,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use version_compare::{compare, Cmp};
+
+use crate::{
+    config::Config,
+    log_err,
+    utils::{dirs, help},
+};
+
+use super::{
+    manifest::{self, Manifest},
+    node, version_file,
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalVersion {
+    /// the version manager which installed it
+    /// `nvm`, `fnm`, `volta`, `n` or `asdf`
+    pub tool: String,
+
+    /// node version
+    pub version: String,
+
+    /// the installation path
+    pub path: PathBuf,
+
+    /// whether the node executable exists
+    pub valid: bool,
+
+    /// whether the version is already installed by nvmd
+    pub installed: bool,
+
+    /// whether the version is the default of the tool
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportMode {
+    /// copy the files into the install directory
+    Copy,
+
+    /// hardlink the files into the install directory (fall back to copy)
+    Hardlink,
+
+    /// register a symbolic link to the installation in the install directory
+    Register,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResult {
+    /// the version manager which installed it
+    pub tool: String,
+
+    /// node version
+    pub version: String,
+
+    /// error message when the import failed
+    pub error: Option<String>,
+}
+
+/// the installations of one version manager
+struct ToolLayout {
+    /// tool name
+    tool: &'static str,
+
+    /// the directory containing version directories
+    versions_dir: PathBuf,
+
+    /// the sub path of the node installation in the version directory
+    sub_path: Option<&'static str>,
+
+    /// the default version of the tool, it may be a spec resolved against the found versions
+    /// e.g. `18`, `node` or `lts/*` of nvm
+    default: Option<String>,
+}
+
+/// return the version if the name looks like `v18.19.0` or `18.19.0`
+fn normalize_version(name: &str) -> Option<String> {
+    let version = name.trim().trim_start_matches('v');
+    let parts = version.split('.').collect::<Vec<_>>();
+    if parts.len() == 3
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+    {
+        Some(version.to_string())
+    } else {
+        None
+    }
+}
+
+fn env_dir(key: &str) -> Option<PathBuf> {
+    env::var_os(key)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// read the nvm alias, an alias may point to another one
+/// e.g. `default` -> `lts/*` -> `lts/iron` -> `v20.11.1`
+fn nvm_alias(root: &Path, name: &str) -> Option<String> {
+    let alias_dir = root.join("alias");
+    let mut spec = help::read_string(&alias_dir.join(name))
+        .ok()?
+        .trim()
+        .to_string();
+    // the aliases may point to each other, follow a few of them only
+    for _ in 0..8 {
+        match help::read_string(&alias_dir.join(&spec)) {
+            Ok(next) if !next.trim().is_empty() => spec = next.trim().to_string(),
+            _ => break,
+        }
+    }
+    Some(spec)
+}
+
+fn nvm_layout(home: &Path) -> ToolLayout {
+    if cfg!(target_os = "windows") {
+        // nvm-windows
+        let root = env_dir("NVM_HOME")
+            .or_else(|| ::dirs::data_dir().map(|dir| dir.join("nvm")))
+            .unwrap_or_default();
+        return ToolLayout {
+            tool: "nvm",
+            versions_dir: root,
+            sub_path: None,
+            default: None,
+        };
+    }
+
+    let root = env_dir("NVM_DIR").unwrap_or_else(|| home.join(".nvm"));
+    let default = nvm_alias(&root, "default");
+    ToolLayout {
+        tool: "nvm",
+        versions_dir: root.join("versions/node"),
+        sub_path: None,
+        default,
+    }
+}
+
+fn fnm_layout(home: &Path) -> ToolLayout {
+    let root = env_dir("FNM_DIR")
+        .or_else(|| {
+            let legacy = home.join(".fnm");
+            legacy.exists().then_some(legacy)
+        })
+        .or_else(|| ::dirs::data_dir().map(|dir| dir.join("fnm")))
+        .unwrap_or_default();
+    // `aliases/default` links to `node-versions/{version}/installation`
+    let default = fs::read_link(root.join("aliases/default"))
+        .ok()
+        .and_then(|link| {
+            link.parent()
+                .and_then(|parent| parent.file_name())
+                .and_then(|name| normalize_version(&name.to_string_lossy()))
+        });
+    ToolLayout {
+        tool: "fnm",
+        versions_dir: root.join("node-versions"),
+        sub_path: Some("installation"),
+        default,
+    }
+}
+
+fn volta_layout(home: &Path) -> ToolLayout {
+    let root = env_dir("VOLTA_HOME")
+        .or_else(|| {
+            if cfg!(target_os = "windows") {
+                ::dirs::data_local_dir().map(|dir| dir.join("Volta"))
+            } else {
+                Some(home.join(".volta"))
+            }
+        })
+        .unwrap_or_default();
+    let default = help::read_json::<serde_json::Value>(&root.join("tools/user/platform.json"))
+        .ok()
+        .and_then(|platform| {
+            platform["node"]["runtime"]
+                .as_str()
+                .and_then(normalize_version)
+        });
+    ToolLayout {
+        tool: "volta",
+        versions_dir: root.join("tools/image/node"),
+        sub_path: None,
+        default,
+    }
+}
+
+fn n_layout() -> ToolLayout {
+    let prefix = env_dir("N_PREFIX").unwrap_or_else(|| PathBuf::from("/usr/local"));
+    ToolLayout {
+        tool: "n",
+        versions_dir: prefix.join("n/versions/node"),
+        sub_path: None,
+        default: None,
+    }
+}
+
+fn asdf_layout(home: &Path) -> ToolLayout {
+    let root = env_dir("ASDF_DATA_DIR").unwrap_or_else(|| home.join(".asdf"));
+    let default = help::read_string(&home.join(".tool-versions"))
+        .ok()
+        .and_then(|content| {
+            content.lines().find_map(|line| {
+                let mut parts = line.split_whitespace();
+                match (parts.next(), parts.next()) {
+                    (Some("nodejs"), Some(version)) => normalize_version(version),
+                    _ => None,
+                }
+            })
+        });
+    ToolLayout {
+        tool: "asdf",
+        versions_dir: root.join("installs/nodejs"),
+        sub_path: None,
+        default,
+    }
+}
+
+/// find the node installations of other version managers
+pub async fn discover_versions() -> Result<Vec<ExternalVersion>> {
+    let home = ::dirs::home_dir().unwrap_or_default();
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+    let node_list = { Config::node().latest().get_list() }.unwrap_or_default();
+
+    let mut layouts = vec![nvm_layout(&home), fnm_layout(&home), volta_layout(&home)];
+    if cfg!(not(target_os = "windows")) {
+        layouts.push(n_layout());
+    }
+    layouts.push(asdf_layout(&home));
+
+    let mut list = vec![];
+    for layout in layouts {
+        if !layout.versions_dir.is_dir() {
+            continue;
+        }
+
+        let mut found = vec![];
+        for entry in fs::read_dir(&layout.versions_dir)?.flatten() {
+            let Some(version) = normalize_version(&entry.file_name().to_string_lossy()) else {
+                continue;
+            };
+            let path = match layout.sub_path {
+                Some(sub_path) => entry.path().join(sub_path),
+                None => entry.path(),
+            };
+            found.push(ExternalVersion {
+                tool: layout.tool.to_string(),
+                valid: dirs::node_exe_path(&path).exists(),
+                installed: installed.contains(&version),
+                is_default: false,
+                version,
+                path,
+            });
+        }
+        found.sort_by(|a, b| match compare(&b.version, &a.version) {
+            Ok(Cmp::Lt) => Ordering::Less,
+            Ok(Cmp::Gt) => Ordering::Greater,
+            _ => Ordering::Equal,
+        });
+        let versions = found
+            .iter()
+            .map(|item| item.version.clone())
+            .collect::<Vec<_>>();
+        let default = layout
+            .default
+            .and_then(|spec| version_file::resolve_spec(&spec, &versions, &node_list));
+        for item in found.iter_mut() {
+            item.is_default = default.as_ref() == Some(&item.version);
+        }
+        list.extend(found);
+    }
+
+    Ok(list)
+}
+
+/// copy the directory recursively, hardlink the files when `hardlink` is `true`
+fn copy_tree(source: &Path, dest: &Path, hardlink: bool) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = dest.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_tree(&entry.path(), &target, hardlink)?;
+        } else if file_type.is_symlink() {
+            copy_symlink(&entry.path(), &target, hardlink)?;
+        } else if !hardlink || fs::hard_link(entry.path(), &target).is_err() {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn copy_symlink(source: &Path, dest: &Path, _hardlink: bool) -> Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(source)?, dest)?;
+    Ok(())
+}
+
+#[cfg(windows)]
+fn copy_symlink(source: &Path, dest: &Path, hardlink: bool) -> Result<()> {
+    if fs::metadata(source)?.is_dir() {
+        copy_tree(source, dest, hardlink)
+    } else {
+        fs::copy(source, dest)?;
+        Ok(())
+    }
+}
+
+/// import one installation into the install directory
+async fn import_version(item: &ExternalVersion, directory: &Path, mode: ImportMode) -> Result<()> {
+    if !dirs::node_exe_path(&item.path).exists() {
+        bail!(
+            "the node executable is missing in \"{}\"",
+            item.path.display()
+        );
+    }
+
+    let dest = directory.join(&item.version);
+    if dest.exists() {
+        bail!("the version \"{}\" is already installed", item.version);
+    }
+
+    if let ImportMode::Register = mode {
+        return help::symlink_dir(&item.path, &dest);
+    }
+
+    // copy to a temporary directory first, so a half-copied version is never listed
+    let temp = directory.join(format!(".{}.import", item.version));
+    if temp.exists() {
+        tokio::fs::remove_dir_all(&temp).await?;
+    }
+    let source = item.path.clone();
+    let temp_dir = temp.clone();
+    let hardlink = matches!(mode, ImportMode::Hardlink);
+    let copied =
+        tokio::task::spawn_blocking(move || copy_tree(&source, &temp_dir, hardlink)).await?;
+    if let Err(err) = copied {
+        log_err!(tokio::fs::remove_dir_all(&temp).await);
+        return Err(err);
+    }
+    tokio::fs::rename(&temp, &dest).await?;
+
+    let record = Manifest {
+        version: item.version.clone(),
+        installed_at: Some(manifest::now_millis()),
+        source: Some(format!("import:{}", item.tool)),
+        ..Manifest::default()
+    };
+    log_err!(manifest::write_manifest(&dest, &record).await);
+
+    Ok(())
+}
+
+/// import the installations into the install directory
+/// use the tool's default as the global version when `set_default` is `true`
+pub async fn import_versions(
+    list: Vec<ExternalVersion>,
+    mode: ImportMode,
+    set_default: Option<bool>,
+) -> Result<Vec<ImportResult>> {
+    let directory = Config::settings()
+        .latest()
+        .get_directory()
+        .unwrap_or_default();
+    let directory = PathBuf::from(directory);
+    if !directory.exists() {
+        tokio::fs::create_dir_all(&directory).await?;
+    }
+
+    let mut results = vec![];
+    for item in &list {
+        let error = import_version(item, &directory, mode)
+            .await
+            .err()
+            .map(|err| err.to_string());
+        results.push(ImportResult {
+            tool: item.tool.clone(),
+            version: item.version.clone(),
+            error,
+        });
+    }
+
+    node::get_installed_list(Some(true)).await?;
+
+    if set_default.unwrap_or(false) {
+        let default = list.iter().find(|item| {
+            item.is_default
+                && results
+                    .iter()
+                    .any(|ret| ret.version == item.version && ret.error.is_none())
+        });
+        if let Some(default) = default {
+            node::set_current(Some(default.version.clone())).await?;
+        }
+    }
+
+    Ok(results)
+}
+
+#[test]
+fn test_normalize_version() {
+    assert_eq!(normalize_version("v18.19.0"), Some("18.19.0".to_string()));
+    assert_eq!(normalize_version("20.11.1\n"), Some("20.11.1".to_string()));
+    assert_eq!(normalize_version("lts/*"), None);
+    assert_eq!(normalize_version("18"), None);
+}
+
+// `*` is not a valid file name on Windows, where nvm-windows has no aliases
+#[cfg(unix)]
+#[test]
+fn test_nvm_alias() {
+    let root = env::temp_dir().join(format!("nvmd-nvm-alias-{}", std::process::id()));
+    fs::create_dir_all(root.join("alias/lts")).unwrap();
+    fs::write(root.join("alias/default"), "lts/*\n").unwrap();
+    fs::write(root.join("alias/lts/*"), "lts/iron\n").unwrap();
+    fs::write(root.join("alias/lts/iron"), "v20.11.1\n").unwrap();
+    fs::write(root.join("alias/work"), "18\n").unwrap();
+
+    assert_eq!(nvm_alias(&root, "default"), Some("v20.11.1".to_string()));
+    assert_eq!(nvm_alias(&root, "work"), Some("18".to_string()));
+    assert_eq!(nvm_alias(&root, "missing"), None);
+
+    let versions = ["20.11.1", "18.19.0", "18.17.1"].map(String::from);
+    assert_eq!(
+        version_file::resolve_spec("18", &versions, &[]),
+        Some("18.19.0".to_string())
+    );
+
+    fs::remove_dir_all(&root).unwrap();
+}
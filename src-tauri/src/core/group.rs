@@ -1,9 +1,12 @@
 use crate::{
-    config::{Config, Group}, log_err, utils::{dirs, help}
+    config::{Config, Group},
+    log_err,
+    utils::{dirs, help},
 };
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use std::path::PathBuf;
 
-use super::handle;
+use super::{alias, handle, project, version_file};
 
 /// get project list from `projects.json`
 pub async fn group_list(fetch: Option<bool>) -> Result<Option<Vec<Group>>> {
@@ -33,11 +36,64 @@ pub async fn update_groups(list: Vec<Group>) -> Result<()> {
     Ok(())
 }
 
-/// update group version
-pub async fn update_group_version(name: String, version: String) -> Result<()> {
-    Config::groups().draft().update_version(name, version)?;
+/// update the group & member versions in the drafts & save them
+/// the drafts are applied by the caller only when both files are saved
+fn save_group_version(name: &str, version: &str, paths: &[String]) -> Result<()> {
+    Config::groups()
+        .draft()
+        .update_version(name.to_string(), version.to_string())?;
+    Config::projects().draft().update_versions(paths, name)?;
+    Config::groups().draft().save_file()?;
+    Config::projects().draft().save_file()
+}
+
+/// update group version & the version files of the member projects
+/// nothing is changed when any member project fails
+pub async fn update_group_version(name: String, version: String) -> Result<project::BatchUpdate> {
+    let members = { Config::groups().latest().get_list() }
+        .unwrap_or_default()
+        .into_iter()
+        .find(|group| group.name == name)
+        .map(|group| group.projects)
+        .ok_or_else(|| anyhow!("failed to find the group item \"name:{name}\""))?;
+
+    // the group members are the project ids
+    let members = { Config::projects().latest().get_list() }
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|project| members.contains(&project.id))
+        .map(|project| project.path)
+        .collect::<Vec<_>>();
+
+    let resolved = alias::resolve_version(&version)?;
+    let paths = members.iter().map(PathBuf::from).collect::<Vec<_>>();
+    let (results, snapshots) = project::write_versions(paths, &resolved).await;
+    if results.iter().any(|result| result.error.is_some()) {
+        return Ok(project::BatchUpdate {
+            results,
+            applied: false,
+        });
+    }
+
+    if let Err(err) = save_group_version(&name, &version, &members) {
+        Config::groups().discard();
+        Config::projects().discard();
+        // the files may have been saved before the failure
+        log_err!(Config::groups().data().save_file());
+        log_err!(Config::projects().data().save_file());
+        log_err!(version_file::restore(&snapshots).await);
+        return Err(err);
+    }
     Config::groups().apply();
-    Config::groups().data().save_file()?;
+    Config::projects().apply();
 
-    Ok(())
+    log_err!(handle::Handle::update_systray_part_with_emit(
+        "call-projects-update",
+        &resolved
+    ));
+
+    Ok(project::BatchUpdate {
+        results,
+        applied: true,
+    })
 }
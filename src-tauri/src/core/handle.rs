@@ -2,8 +2,9 @@ use super::tray::Tray;
 use anyhow::{bail, Result};
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
+use serde::Serialize;
 use std::sync::Arc;
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter, Manager};
 
 #[derive(Debug, Default, Clone)]
 pub struct Handle {
@@ -51,4 +52,16 @@ impl Handle {
         Tray::update_part_with_emit(app_handle.as_ref().unwrap(), event, version)?;
         Ok(())
     }
+
+    /// emit an event to the main window
+    pub fn emit_to_main<S: Serialize + Clone>(event: &str, payload: S) -> Result<()> {
+        let app_handle = Self::global().app_handle.lock();
+        if app_handle.is_none() {
+            bail!("emit_to_main unhandled error");
+        }
+        if let Some(window) = app_handle.as_ref().unwrap().get_webview_window("main") {
+            window.emit(event, payload)?;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,286 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{Config, Group, Project},
+    log_err,
+    utils::help,
+};
+
+use super::{alias, handle, project, version_file};
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HealthIssueKind {
+    /// the project directory no longer exists
+    MissingPath,
+
+    /// the project (or its group) uses a version not installed
+    NotInstalled,
+
+    /// the project uses a group which no longer exists
+    MissingGroup,
+
+    /// the version files differ from `projects.json`
+    Drifted,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthIssue {
+    /// the problem found
+    pub kind: HealthIssueKind,
+
+    /// project name
+    pub name: String,
+
+    /// project path
+    pub path: String,
+
+    /// the version expected by `projects.json`
+    pub version: Option<String>,
+
+    /// the group the project uses
+    pub group: Option<String>,
+
+    /// the content of the drifted version file
+    pub actual: Option<String>,
+}
+
+/// the version the project expects, resolved through its group & alias
+enum Expected {
+    /// no version set
+    None,
+
+    /// the resolved version & the group used
+    Version(String, Option<String>),
+
+    /// the problem found
+    Issue(HealthIssueKind, Option<String>, Option<String>),
+}
+
+/// whether the project version looks like a version rather than a group or an alias
+fn is_version_like(version: &str) -> bool {
+    version
+        .trim_start_matches('v')
+        .starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// resolve the version the project expects
+fn expected_version(project: &Project, groups: &[Group], installed: &[String]) -> Expected {
+    let Some(version) = project.version.as_deref() else {
+        return Expected::None;
+    };
+
+    let mut group_name = None;
+    let mut version = version.to_string();
+    if !is_version_like(&version) && alias::find_alias(&version).is_none() {
+        let Some(group) = groups.iter().find(|group| group.name == version) else {
+            return Expected::Issue(HealthIssueKind::MissingGroup, None, Some(version));
+        };
+        let Some(group_version) = group.version.clone() else {
+            return Expected::None;
+        };
+        group_name = Some(group.name.clone());
+        version = group_version;
+    }
+
+    match alias::resolve_version(&version) {
+        Ok(resolved) if installed.contains(&resolved) => Expected::Version(resolved, group_name),
+        Ok(resolved) => Expected::Issue(HealthIssueKind::NotInstalled, Some(resolved), group_name),
+        Err(_) => Expected::Issue(HealthIssueKind::NotInstalled, Some(version), group_name),
+    }
+}
+
+/// read the plain version files which differ from the expected version
+async fn drifted_content(path: &Path, version: &str) -> Option<String> {
+    let plain = help::split_install_name(version).0;
+    for file in version_file::maintained_files(path) {
+        let expected = match file.as_str() {
+            ".nvmdrc" => version,
+            ".nvmrc" | ".node-version" => plain,
+            _ => continue,
+        };
+        let actual = version_file::read_spec(&path.join(&file)).await;
+        if actual.as_deref() != Some(expected) {
+            return Some(actual.unwrap_or_default());
+        }
+    }
+    None
+}
+
+/// check every project for missing directories, versions not installed,
+/// groups no longer existing & drifted version files
+pub async fn project_health() -> Result<Vec<HealthIssue>> {
+    let projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+    let groups = { Config::groups().latest().get_list() }.unwrap_or_default();
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+
+    let mut issues = vec![];
+    for project in &projects {
+        let issue = |kind, version, group, actual| HealthIssue {
+            kind,
+            name: project.name.clone(),
+            path: project.path.clone(),
+            version,
+            group,
+            actual,
+        };
+
+        let path = PathBuf::from(&project.path);
+        if !path.exists() {
+            issues.push(issue(HealthIssueKind::MissingPath, None, None, None));
+            continue;
+        }
+
+        match expected_version(project, &groups, &installed) {
+            Expected::None => {}
+            Expected::Issue(kind, version, group) => {
+                issues.push(issue(kind, version, group, None));
+            }
+            Expected::Version(version, group) => {
+                if let Some(actual) = drifted_content(&path, &version).await {
+                    issues.push(issue(
+                        HealthIssueKind::Drifted,
+                        Some(version),
+                        group,
+                        Some(actual),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(issues)
+}
+
+/// get the newest installed version of the same major line
+fn replacement(version: &str, installed: &[String]) -> Option<String> {
+    let plain = help::split_install_name(version).0.trim_start_matches('v');
+    let major = plain.split('.').next()?;
+    installed
+        .iter()
+        .find(|item| {
+            help::split_install_name(item).1.is_none() && item.split('.').next() == Some(major)
+        })
+        .cloned()
+}
+
+/// fix the issues of the kinds, return the issues left
+/// - missing path: remove the project from the projects & groups
+/// - missing group: use the version in `.nvmdrc` if installed, otherwise unset the version
+/// - not installed: use the newest installed version of the same major line (for the group if used)
+/// - drifted: rewrite the version files
+pub async fn fix_project_health(kinds: Vec<HealthIssueKind>) -> Result<Vec<HealthIssue>> {
+    let issues = project_health()
+        .await?
+        .into_iter()
+        .filter(|issue| kinds.contains(&issue.kind))
+        .collect::<Vec<_>>();
+    if issues.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+    let mut groups = { Config::groups().latest().get_list() }.unwrap_or_default();
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+
+    // the projects whose version files need to be rewritten
+    let mut to_sync = vec![];
+    for issue in &issues {
+        match issue.kind {
+            HealthIssueKind::MissingPath => {
+                let ids = projects
+                    .iter()
+                    .filter(|project| project.path == issue.path)
+                    .map(|project| project.id.clone())
+                    .collect::<Vec<_>>();
+                projects.retain(|project| project.path != issue.path);
+                for group in groups.iter_mut() {
+                    group.projects.retain(|id| !ids.contains(id));
+                }
+            }
+            HealthIssueKind::MissingGroup => {
+                let path = PathBuf::from(&issue.path);
+                let current = version_file::read_spec(&path.join(".nvmdrc"))
+                    .await
+                    .filter(|version| installed.contains(version));
+                if let Some(project) = projects.iter_mut().find(|p| p.path == issue.path) {
+                    project.version = current;
+                }
+            }
+            HealthIssueKind::NotInstalled => {
+                let Some(version) = issue
+                    .version
+                    .as_deref()
+                    .and_then(|version| replacement(version, &installed))
+                else {
+                    continue;
+                };
+                match &issue.group {
+                    // the group members follow the new group version
+                    Some(name) => {
+                        if let Some(group) = groups.iter_mut().find(|g| &g.name == name) {
+                            group.version = Some(version.clone());
+                            let members = projects
+                                .iter()
+                                .filter(|project| group.projects.contains(&project.id));
+                            for project in members {
+                                to_sync.push((project.path.clone(), version.clone()));
+                            }
+                        }
+                    }
+                    None => {
+                        if let Some(project) = projects.iter_mut().find(|p| p.path == issue.path) {
+                            project.version = Some(version.clone());
+                        }
+                    }
+                }
+                to_sync.push((issue.path.clone(), version));
+            }
+            HealthIssueKind::Drifted => {
+                if let Some(version) = &issue.version {
+                    to_sync.push((issue.path.clone(), version.clone()));
+                }
+            }
+        }
+    }
+
+    Config::projects().draft().update_list(&projects)?;
+    Config::projects().apply();
+    Config::projects().data().save_file()?;
+    Config::groups().draft().update_list(&groups)?;
+    Config::groups().apply();
+    Config::groups().data().save_file()?;
+
+    to_sync.sort();
+    to_sync.dedup();
+    for (path, version) in to_sync {
+        log_err!(project::sync_project_version(PathBuf::from(path), &version).await);
+    }
+
+    log_err!(handle::Handle::update_systray_part_with_emit(
+        "call-projects-update",
+        ""
+    ));
+
+    project_health().await
+}
+
+#[test]
+fn test_replacement() {
+    let installed = ["20.11.1", "18.19.0-x64", "18.18.2", "16.20.2"].map(String::from);
+    assert_eq!(
+        replacement("18.17.0", &installed),
+        Some("18.18.2".to_string())
+    );
+    assert_eq!(
+        replacement("v20.0.0-arm64", &installed),
+        Some("20.11.1".to_string())
+    );
+    assert_eq!(replacement("14.21.3", &installed), None);
+    assert!(is_version_like("v18"));
+    assert!(!is_version_like("frontend"));
+}
@@ -0,0 +1,407 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use futures::{stream, StreamExt};
+use get_node::checksum::{file_checksum, shasums, ShasumsConfig};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{Config, ISettings},
+    utils::{dirs, help},
+};
+
+use super::{
+    manifest::{self, FILES_NAME, MANIFEST_NAME},
+    node,
+};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IntegrityStatus {
+    /// all recorded files are unchanged
+    Ok,
+
+    /// the node executable is missing
+    Missing,
+
+    /// files were modified or deleted, or the archive checksum differs from the mirror
+    Corrupt,
+
+    /// no file hashes were recorded before, they are recorded now
+    Unverified,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionIntegrity {
+    /// the installation directory name
+    pub version: String,
+
+    /// integrity status
+    pub status: IntegrityStatus,
+
+    /// the modified files (relative path)
+    pub modified: Vec<String>,
+
+    /// the deleted files (relative path)
+    pub missing: Vec<String>,
+
+    /// extra information
+    pub message: Option<String>,
+}
+
+/// the global packages directory, changed by `npm i -g` (npm & corepack are bundled there)
+#[cfg(not(windows))]
+const GLOBAL_PACKAGES_DIR: &str = "lib/node_modules/";
+#[cfg(windows)]
+const GLOBAL_PACKAGES_DIR: &str = "node_modules/";
+
+/// whether the file (relative path) is a command shim written by `npm i -g`
+/// on Windows, npm writes `{bin}`, `{bin}.cmd` and `{bin}.ps1` into the version root
+/// elsewhere the shims are symbolic links in `bin/`, which are never hashed
+#[cfg(windows)]
+fn is_shim(relative: &str) -> bool {
+    if relative.contains('/') {
+        return false;
+    }
+    match Path::new(relative).extension() {
+        None => true,
+        Some(ext) => ext.eq_ignore_ascii_case("cmd") || ext.eq_ignore_ascii_case("ps1"),
+    }
+}
+#[cfg(not(windows))]
+fn is_shim(_relative: &str) -> bool {
+    false
+}
+
+/// whether the file (relative path) is checked, the global packages and their shims are excluded
+fn is_checked(relative: &str) -> bool {
+    relative != MANIFEST_NAME
+        && relative != FILES_NAME
+        && !relative.starts_with(GLOBAL_PACKAGES_DIR)
+        && !is_shim(relative)
+}
+
+/// collect the regular files in the directory recursively
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_files(&entry.path(), files)?;
+        } else if file_type.is_file() {
+            files.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+/// calculate the sha256 checksum of every file in the version directory except the global packages
+/// key is the relative path with `/` separators
+pub fn hash_files(version_dir: &Path) -> Result<BTreeMap<String, String>> {
+    let mut files = vec![];
+    collect_files(version_dir, &mut files)?;
+
+    let mut hashes = BTreeMap::new();
+    for file in files {
+        let relative = file
+            .strip_prefix(version_dir)?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if !is_checked(&relative) {
+            continue;
+        }
+        let checksum = file_checksum(&file)
+            .with_context(|| format!("failed to hash the file \"{}\"", file.display()))?;
+        hashes.insert(relative, checksum);
+    }
+
+    Ok(hashes)
+}
+
+/// record the file hashes of the version directory
+pub async fn write_file_hashes(version_dir: PathBuf) -> Result<()> {
+    let path = version_dir.join(FILES_NAME);
+    let hashes = tokio::task::spawn_blocking(move || hash_files(&version_dir)).await??;
+    help::async_save_json(&path, &hashes, None).await
+}
+
+/// compare the files with the recorded hashes
+/// return (modified, missing)
+fn diff_files(version_dir: &Path, hashes: &BTreeMap<String, String>) -> (Vec<String>, Vec<String>) {
+    let mut modified = vec![];
+    let mut missing = vec![];
+    // the hashes recorded before the global packages were excluded
+    for (relative, checksum) in hashes.iter().filter(|(relative, _)| is_checked(relative)) {
+        let path = version_dir.join(relative);
+        if !path.exists() {
+            missing.push(relative.clone());
+            continue;
+        }
+        match file_checksum(&path) {
+            Ok(current) if &current == checksum => {}
+            _ => modified.push(relative.clone()),
+        }
+    }
+    (modified, missing)
+}
+
+/// check the archive checksum recorded at install time against the mirror's `SHASUMS256.txt`
+async fn verify_archive(version_dir: &Path, name: &str, settings: &ISettings) -> Result<bool> {
+    let manifest = manifest::read_manifest(version_dir, name).await?;
+    let (Some(archive), Some(checksum)) = (manifest.archive, manifest.checksum) else {
+        return Ok(true);
+    };
+    let mirror = manifest
+        .source
+        .or_else(|| settings.mirror.clone())
+        .unwrap_or_default();
+
+    let shasums = shasums(ShasumsConfig {
+        mirror,
+        version: manifest.version,
+        no_proxy: settings.no_proxy,
+        proxy: settings.proxy.clone(),
+        timeout: None,
+    })
+    .await?;
+
+    Ok(shasums.get(&archive) == Some(&checksum))
+}
+
+/// verify one version directory
+async fn verify_version(
+    version_dir: PathBuf,
+    version: String,
+    settings: &ISettings,
+) -> VersionIntegrity {
+    let mut integrity = VersionIntegrity {
+        version: version.clone(),
+        status: IntegrityStatus::Ok,
+        modified: vec![],
+        missing: vec![],
+        message: None,
+    };
+
+    if !dirs::node_exe_path(&version_dir).exists() {
+        integrity.status = IntegrityStatus::Missing;
+        integrity.message = Some("the node executable is missing".to_string());
+        return integrity;
+    }
+
+    // the versions linked from read-only sources or other version managers are not written
+    if help::is_symlink(&version_dir) {
+        integrity.status = IntegrityStatus::Unverified;
+        integrity.message =
+            Some("the version is linked from another directory, its files are not recorded".into());
+        return integrity;
+    }
+
+    let files_path = version_dir.join(FILES_NAME);
+    if !files_path.exists() {
+        // trust on first use, record the hashes for the next check
+        integrity.status = IntegrityStatus::Unverified;
+        if let Err(err) = write_file_hashes(version_dir.clone()).await {
+            integrity.message = Some(err.to_string());
+        }
+    } else {
+        match help::async_read_json::<BTreeMap<String, String>>(&files_path).await {
+            Ok(hashes) => {
+                let dir = version_dir.clone();
+                match tokio::task::spawn_blocking(move || diff_files(&dir, &hashes)).await {
+                    Ok((modified, missing)) => {
+                        if !modified.is_empty() || !missing.is_empty() {
+                            integrity.status = IntegrityStatus::Corrupt;
+                        }
+                        integrity.modified = modified;
+                        integrity.missing = missing;
+                    }
+                    Err(err) => integrity.message = Some(err.to_string()),
+                }
+            }
+            Err(err) => integrity.message = Some(err.to_string()),
+        }
+    }
+
+    match verify_archive(&version_dir, &version, settings).await {
+        Ok(true) => {}
+        Ok(false) => {
+            integrity.status = IntegrityStatus::Corrupt;
+            integrity.message =
+                Some("the archive checksum does not match the mirror's SHASUMS".to_string());
+        }
+        Err(err) => {
+            if integrity.message.is_none() {
+                integrity.message = Some(format!("failed to check the SHASUMS: {err}"));
+            }
+        }
+    }
+
+    integrity
+}
+
+/// verify every version directory in the install directory
+pub async fn verify_installed() -> Result<Vec<VersionIntegrity>> {
+    let settings = Config::settings().latest().clone();
+    let directory = PathBuf::from(settings.get_directory().unwrap_or_default());
+    if !directory.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut versions = vec![];
+    let mut entries = tokio::fs::read_dir(&directory).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let version = entry.file_name().to_string_lossy().to_string();
+        if entry.file_type().await?.is_dir() && !version.starts_with('.') {
+            versions.push(version);
+        }
+    }
+    versions.sort_by(|a, b| help::compare_installed(a, b));
+
+    let settings = &settings;
+    let report = stream::iter(versions)
+        .map(|version| verify_version(directory.join(&version), version, settings))
+        .buffered(2)
+        .collect::<Vec<_>>()
+        .await;
+
+    Ok(report)
+}
+
+/// reinstall the version
+/// the old directory is restored when the installation fails
+/// name: the installation directory name, `{version}` or `{version}-{arch}`
+pub async fn reinstall_node(window: tauri::Window, name: String) -> Result<String> {
+    let directory = Config::settings()
+        .latest()
+        .get_directory()
+        .unwrap_or_default();
+    let version_dir = PathBuf::from(&directory).join(&name);
+    if help::is_symlink(&version_dir) {
+        bail!("the version \"{name}\" is linked from another directory and cannot be reinstalled");
+    }
+    let backup_dir = PathBuf::from(&directory).join(format!(".{name}.repair"));
+    let (version, name_arch) = help::split_install_name(&name);
+    let version = version.to_string();
+    let name_arch = name_arch.map(|arch| arch.to_string());
+
+    let arch = if version_dir.exists() {
+        let arch = manifest::read_manifest(&version_dir, &name)
+            .await
+            .ok()
+            .and_then(|manifest| manifest.arch)
+            .or(name_arch);
+        if backup_dir.exists() {
+            tokio::fs::remove_dir_all(&backup_dir).await?;
+        }
+        tokio::fs::rename(&version_dir, &backup_dir).await?;
+        arch
+    } else {
+        name_arch
+    };
+
+    match node::install_node(window, Some(version), arch, None).await {
+        Ok(path) => {
+            if backup_dir.exists() {
+                tokio::fs::remove_dir_all(&backup_dir).await?;
+            }
+            node::get_installed_list(Some(true)).await?;
+            Ok(path)
+        }
+        Err(err) => {
+            if backup_dir.exists() {
+                if version_dir.exists() {
+                    tokio::fs::remove_dir_all(&version_dir).await?;
+                }
+                tokio::fs::rename(&backup_dir, &version_dir).await?;
+            }
+            Err(err)
+        }
+    }
+}
+
+#[test]
+fn test_is_checked() {
+    assert!(is_checked("bin/node"));
+    assert!(is_checked("include/node/node.h"));
+    assert!(!is_checked(MANIFEST_NAME));
+    assert!(!is_checked(&format!(
+        "{GLOBAL_PACKAGES_DIR}npm/package.json"
+    )));
+    #[cfg(windows)]
+    {
+        assert!(is_checked("node.exe"));
+        assert!(!is_checked("npm.cmd"));
+        assert!(!is_checked("tsc.ps1"));
+        assert!(!is_checked("tsc"));
+    }
+}
+
+#[test]
+fn test_global_package_installed() {
+    let version_dir = std::env::temp_dir().join(format!("nvmd-integrity-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&version_dir);
+    let node = dirs::node_exe_path(&version_dir);
+    fs::create_dir_all(node.parent().unwrap()).unwrap();
+    fs::write(&node, "node").unwrap();
+    fs::create_dir_all(version_dir.join(GLOBAL_PACKAGES_DIR).join("npm")).unwrap();
+    fs::write(
+        version_dir
+            .join(GLOBAL_PACKAGES_DIR)
+            .join("npm/package.json"),
+        "{}",
+    )
+    .unwrap();
+    #[cfg(windows)]
+    fs::write(version_dir.join("npm.cmd"), "@npm").unwrap();
+    let hashes = hash_files(&version_dir).unwrap();
+
+    // `npm i -g typescript` writes the package and its shims
+    let package = version_dir.join(GLOBAL_PACKAGES_DIR).join("typescript");
+    fs::create_dir_all(&package).unwrap();
+    fs::write(package.join("package.json"), "{}").unwrap();
+    #[cfg(windows)]
+    for shim in ["tsc", "tsc.cmd", "tsc.ps1"] {
+        fs::write(version_dir.join(shim), "@tsc").unwrap();
+    }
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(
+        "../lib/node_modules/typescript/bin/tsc",
+        version_dir.join("bin/tsc"),
+    )
+    .unwrap();
+    // `npm i -g npm@latest` rewrites the bundled npm and its shims
+    fs::write(
+        version_dir
+            .join(GLOBAL_PACKAGES_DIR)
+            .join("npm/package.json"),
+        "{ }",
+    )
+    .unwrap();
+    #[cfg(windows)]
+    fs::write(version_dir.join("npm.cmd"), "@npm latest").unwrap();
+    assert_eq!(diff_files(&version_dir, &hashes), (vec![], vec![]));
+
+    // `npm uninstall -g typescript`
+    fs::remove_dir_all(&package).unwrap();
+    #[cfg(windows)]
+    for shim in ["tsc", "tsc.cmd", "tsc.ps1"] {
+        fs::remove_file(version_dir.join(shim)).unwrap();
+    }
+    #[cfg(unix)]
+    fs::remove_file(version_dir.join("bin/tsc")).unwrap();
+    assert_eq!(diff_files(&version_dir, &hashes), (vec![], vec![]));
+
+    fs::write(&node, "modified").unwrap();
+    let (modified, missing) = diff_files(&version_dir, &hashes);
+    assert_eq!(modified.len(), 1);
+    assert!(missing.is_empty());
+
+    fs::remove_dir_all(&version_dir).unwrap();
+}
@@ -0,0 +1,253 @@
+use std::{path::PathBuf, process::Stdio};
+
+use anyhow::{bail, Result};
+
+use crate::{
+    config::Config,
+    utils::{dirs, help},
+};
+
+use super::project;
+
+/// the placeholder of the project path in the launch command templates
+const PATH_PLACEHOLDER: &str = "{path}";
+
+/// the default terminal launch command
+/// Terminal.app is driven by `osascript` on macOS, see `open_terminal_app`
+#[cfg(target_os = "windows")]
+const DEFAULT_TERMINAL: &str = "cmd /c start cmd /k cd /d {path}";
+#[cfg(target_os = "linux")]
+const DEFAULT_TERMINAL: &str = "x-terminal-emulator";
+
+/// the default editor launch command
+const DEFAULT_EDITOR: &str = "code {path}";
+
+/// split the command template into the arguments, quotes group the words
+/// `{path}` is replaced in each argument, so a path with spaces stays one argument
+fn build_command(template: &str, path: &str) -> Result<(String, Vec<String>)> {
+    let mut args = vec![];
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut started = false;
+
+    for c in template.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                started = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if started || !current.is_empty() {
+                    args.push(std::mem::take(&mut current));
+                    started = false;
+                }
+            }
+            (None, c) => current.push(c),
+        }
+    }
+    if quote.is_some() {
+        bail!("the launch command \"{template}\" has an unclosed quote");
+    }
+    if started || !current.is_empty() {
+        args.push(current);
+    }
+
+    let mut args = args
+        .into_iter()
+        .map(|arg| arg.replace(PATH_PLACEHOLDER, path));
+    match args.next() {
+        Some(program) => Ok((program, args.collect())),
+        None => bail!("the launch command should not be empty"),
+    }
+}
+
+/// resolve the program against `PATH` & `PATHEXT` like the shell does on Windows
+/// `Command::new` only appends `.exe`, editors install `code.cmd` or `webstorm.cmd`
+#[cfg(any(target_os = "windows", test))]
+fn resolve_program(program: &str, paths: &std::ffi::OsStr, pathext: &str) -> PathBuf {
+    let path = PathBuf::from(program);
+    if path.extension().is_some() || path.components().count() > 1 {
+        return path;
+    }
+    std::env::split_paths(paths)
+        .flat_map(|dir| {
+            pathext
+                .split(';')
+                .filter(|ext| !ext.is_empty())
+                .map(move |ext| dir.join(format!("{program}{}", ext.to_ascii_lowercase())))
+        })
+        .find(|candidate| candidate.is_file())
+        .unwrap_or(path)
+}
+
+/// launch the command at the project with the project's node `bin` directory first in `PATH`
+/// and the project environment variables set
+async fn launch(template: &str, path: PathBuf) -> Result<()> {
+    if !path.exists() {
+        bail!("the project \"{}\" does not exist", path.display());
+    }
+    let version_dir = project::project_version_dir(&path).await?;
+    let (program, args) = build_command(template, &path.to_string_lossy())?;
+    let paths = help::prepend_path(&dirs::node_bin_dir(&version_dir))?;
+    #[cfg(target_os = "windows")]
+    let program = resolve_program(
+        &program,
+        &paths,
+        &std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".into()),
+    );
+
+    let mut child = help::async_command(program)
+        .args(args)
+        .current_dir(&path)
+        .envs(project::project_env(&path))
+        .env("PATH", paths)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    // reap the process when it exits
+    tauri::async_runtime::spawn(async move {
+        let _ = child.wait().await;
+    });
+    Ok(())
+}
+
+/// quote the value for a POSIX shell
+#[cfg(any(target_os = "macos", test))]
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// the shell script run in the new terminal, it enters the project & exports the environment
+/// the node `bin` directory is prepended to the `PATH` of the login shell
+#[cfg(any(target_os = "macos", test))]
+fn terminal_script(
+    path: &str,
+    bin_dir: &str,
+    env: &std::collections::BTreeMap<String, String>,
+) -> String {
+    let mut script = format!("cd {}", shell_quote(path));
+    for (key, value) in env {
+        script.push_str(&format!(" && export {key}={}", shell_quote(value)));
+    }
+    script.push_str(&format!(
+        " && export PATH={}:\"$PATH\"",
+        shell_quote(bin_dir)
+    ));
+    script
+}
+
+/// escape the value for an AppleScript string literal
+#[cfg(target_os = "macos")]
+fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// open Terminal.app at the project through `osascript`
+/// `open -a Terminal` passes neither the working directory nor the environment
+#[cfg(target_os = "macos")]
+async fn open_terminal_app(path: PathBuf) -> Result<()> {
+    if !path.exists() {
+        bail!("the project \"{}\" does not exist", path.display());
+    }
+    let version_dir = project::project_version_dir(&path).await?;
+    let script = terminal_script(
+        &path.to_string_lossy(),
+        &dirs::node_bin_dir(&version_dir).to_string_lossy(),
+        &project::project_env(&path),
+    );
+
+    let output = help::async_command("osascript")
+        .args([
+            "-e",
+            &format!(
+                "tell application \"Terminal\" to do script {}",
+                applescript_string(&script)
+            ),
+            "-e",
+            "tell application \"Terminal\" to activate",
+        ])
+        .output()
+        .await?;
+    if !output.status.success() {
+        bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(())
+}
+
+/// open the terminal at the project
+pub async fn open_terminal(path: PathBuf) -> Result<()> {
+    let template = { Config::settings().latest().get_terminal() }
+        .filter(|template| !template.trim().is_empty());
+    match template {
+        Some(template) => launch(&template, path).await,
+        #[cfg(target_os = "macos")]
+        None => open_terminal_app(path).await,
+        #[cfg(not(target_os = "macos"))]
+        None => launch(DEFAULT_TERMINAL, path).await,
+    }
+}
+
+/// open the editor at the project
+pub async fn open_editor(path: PathBuf) -> Result<()> {
+    let template = { Config::settings().latest().get_editor() }
+        .filter(|template| !template.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+    launch(&template, path).await
+}
+
+#[test]
+fn test_build_command() {
+    let (program, args) = build_command("code {path}", "/work/my app").unwrap();
+    assert_eq!(program, "code");
+    assert_eq!(args, vec!["/work/my app".to_string()]);
+
+    let (program, args) =
+        build_command(r#"open -a "Visual Studio Code" '{path}'"#, "/work/app").unwrap();
+    assert_eq!(program, "open");
+    assert_eq!(args, vec!["-a", "Visual Studio Code", "/work/app"]);
+
+    let (_, args) = build_command("wt -d {path} --title ''", "C:\\work").unwrap();
+    assert_eq!(args, vec!["-d", "C:\\work", "--title", ""]);
+
+    assert!(build_command("  ", "/work/app").is_err());
+    assert!(build_command("code \"{path}", "/work/app").is_err());
+}
+
+#[test]
+fn test_resolve_program() {
+    let root = std::env::temp_dir().join(format!("nvmd-launcher-{}", std::process::id()));
+    let bin = root.join("bin");
+    std::fs::create_dir_all(&bin).unwrap();
+    std::fs::write(bin.join("code.cmd"), "").unwrap();
+    let paths = std::env::join_paths([root.join("missing"), bin.clone()]).unwrap();
+
+    assert_eq!(
+        resolve_program("code", &paths, ".COM;.EXE;.BAT;.CMD"),
+        bin.join("code.cmd")
+    );
+    assert_eq!(
+        resolve_program("idea", &paths, ".EXE;.CMD"),
+        PathBuf::from("idea")
+    );
+    assert_eq!(
+        resolve_program("code.exe", &paths, ".CMD"),
+        PathBuf::from("code.exe")
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_terminal_script() {
+    let env = std::collections::BTreeMap::from([(
+        "NODE_OPTIONS".to_string(),
+        "--max-old-space-size=4096".to_string(),
+    )]);
+    assert_eq!(
+        terminal_script("/work/it's app", "/nvmd/18.19.0/bin", &env),
+        r#"cd '/work/it'\''s app' && export NODE_OPTIONS='--max-old-space-size=4096' && export PATH='/nvmd/18.19.0/bin':"$PATH""#
+    );
+}
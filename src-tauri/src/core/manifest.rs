@@ -0,0 +1,222 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use futures::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::Config,
+    log_err,
+    utils::{dirs, help},
+};
+
+use super::node;
+
+/// the manifest file name in the version directory
+pub const MANIFEST_NAME: &str = ".nvmd-manifest.json";
+
+/// the file hashes manifest name in the version directory
+pub const FILES_NAME: &str = ".nvmd-files.json";
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    /// node version
+    pub version: String,
+
+    /// node architecture
+    pub arch: Option<String>,
+
+    /// install date (milliseconds since the unix epoch)
+    pub installed_at: Option<u64>,
+
+    /// the mirror the version was downloaded from
+    pub source: Option<String>,
+
+    /// the downloaded archive file name
+    pub archive: Option<String>,
+
+    /// the sha256 checksum of the downloaded archive
+    pub checksum: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledVersion {
+    /// the installation directory name, `{version}` or `{version}-{arch}`
+    pub name: String,
+
+    /// node version
+    pub version: String,
+
+    /// node architecture
+    pub arch: Option<String>,
+
+    /// install date (milliseconds since the unix epoch)
+    pub installed_at: Option<u64>,
+
+    /// the mirror the version was downloaded from
+    pub source: Option<String>,
+
+    /// the sha256 checksum of the downloaded archive
+    pub checksum: Option<String>,
+
+    /// size on disk (bytes)
+    pub size: u64,
+
+    /// the bundled npm version
+    pub npm: Option<String>,
+}
+
+/// get the current time in milliseconds
+pub fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// get the architecture from the archive file name
+/// e.g. `node-v20.11.1-darwin-arm64.tar.gz` => `arm64`
+pub fn arch_from_archive(archive: &str) -> Option<String> {
+    archive
+        .trim_end_matches(".tar.gz")
+        .trim_end_matches(".zip")
+        .rsplit('-')
+        .next()
+        .map(|arch| arch.to_string())
+}
+
+/// save the manifest to the version directory
+pub async fn write_manifest(version_dir: &Path, manifest: &Manifest) -> Result<()> {
+    help::async_save_json(&version_dir.join(MANIFEST_NAME), manifest, None).await
+}
+
+/// read the manifest from the version directory
+/// backfill one for the existing installs without manifest, except the linked versions
+/// name: the installation directory name
+pub async fn read_manifest(version_dir: &Path, name: &str) -> Result<Manifest> {
+    let manifest_path = version_dir.join(MANIFEST_NAME);
+    if manifest_path.exists() {
+        return help::async_read_json::<Manifest>(&manifest_path).await;
+    }
+
+    let (version, arch) = help::split_install_name(name);
+    let arch = match arch {
+        Some(arch) => Some(arch.to_string()),
+        None => detect_arch(version_dir).await,
+    };
+    let installed_at = tokio::fs::metadata(version_dir)
+        .await
+        .and_then(|metadata| metadata.created().or_else(|_| metadata.modified()))
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_millis() as u64);
+
+    let manifest = Manifest {
+        version: version.to_string(),
+        arch,
+        installed_at,
+        ..Manifest::default()
+    };
+    // the linked versions belong to read-only sources or other version managers
+    if !help::is_symlink(version_dir) {
+        log_err!(write_manifest(version_dir, &manifest).await);
+    }
+
+    Ok(manifest)
+}
+
+/// get the architecture of the node executable
+async fn detect_arch(version_dir: &Path) -> Option<String> {
+    help::async_command(dirs::node_exe_path(version_dir))
+        .args(["-p", "process.arch"])
+        .output()
+        .await
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| {
+            // use the architecture names of the node distro
+            match String::from_utf8_lossy(&output.stdout).trim() {
+                "ia32" => "x86".to_string(),
+                "arm" => "armv7l".to_string(),
+                arch => arch.to_string(),
+            }
+        })
+}
+
+/// read the bundled npm version
+async fn npm_version(version_dir: &Path) -> Option<String> {
+    let package_path = dirs::node_modules_dir(version_dir).join("npm/package.json");
+    help::async_read_json::<serde_json::Value>(&package_path)
+        .await
+        .ok()
+        .and_then(|package| package["version"].as_str().map(|v| v.to_string()))
+}
+
+/// get the size of the directory (bytes)
+pub fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => dir_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// get the metadata record of the installation
+pub async fn installed_version(version_dir: PathBuf, name: String) -> Result<InstalledVersion> {
+    let manifest = read_manifest(&version_dir, &name).await?;
+    let npm = npm_version(&version_dir).await;
+    let size = tokio::task::spawn_blocking(move || dir_size(&version_dir)).await?;
+
+    Ok(InstalledVersion {
+        name,
+        version: manifest.version,
+        arch: manifest.arch,
+        installed_at: manifest.installed_at,
+        source: manifest.source,
+        checksum: manifest.checksum,
+        size,
+        npm,
+    })
+}
+
+/// get the metadata records of the installed versions
+pub async fn installed_versions(fetch: Option<bool>) -> Result<Vec<InstalledVersion>> {
+    let installed = node::get_installed_list(fetch).await?.unwrap_or_default();
+    let directory = Config::settings()
+        .latest()
+        .get_directory()
+        .unwrap_or_default();
+    let directory = PathBuf::from(directory);
+
+    stream::iter(installed)
+        .map(|name| installed_version(directory.join(&name), name))
+        .buffered(3)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn test_arch_from_archive() {
+    assert_eq!(
+        arch_from_archive("node-v20.11.1-darwin-arm64.tar.gz"),
+        Some("arm64".to_string())
+    );
+    assert_eq!(
+        arch_from_archive("node-v20.11.1-win-x64.zip"),
+        Some("x64".to_string())
+    );
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use get_node::mirror::{benchmark, BenchmarkConfig, MirrorBenchmark};
+
+use crate::config::{Config, ISettings};
+
+/// the built-in mirrors
+const PRESET_MIRRORS: [&str; 2] = [
+    "https://nodejs.org/dist",
+    "https://npmmirror.com/mirrors/node",
+];
+
+/// probe the preset mirrors & user mirrors
+/// write the fastest one into settings when `apply` is `true`
+pub async fn benchmark_mirrors(
+    mirrors: Option<Vec<String>>,
+    apply: Option<bool>,
+) -> Result<Vec<MirrorBenchmark>> {
+    let mut candidates: Vec<String> = PRESET_MIRRORS.iter().map(|m| m.to_string()).collect();
+    for mirror in mirrors.unwrap_or_default() {
+        let mirror = mirror.trim_end_matches('/').to_string();
+        if !mirror.is_empty() && !candidates.contains(&mirror) {
+            candidates.push(mirror);
+        }
+    }
+
+    let settings = Config::settings().latest().clone();
+    let results = benchmark(BenchmarkConfig {
+        mirrors: candidates,
+        no_proxy: settings.no_proxy,
+        proxy: settings.proxy,
+        timeout: None,
+    })
+    .await?;
+
+    if apply.unwrap_or(false) {
+        if let Some(fastest) = results.iter().find(|result| result.error.is_none()) {
+            Config::settings().draft().patch_settings(ISettings {
+                mirror: Some(fastest.mirror.clone()),
+                ..ISettings::default()
+            })?;
+            Config::settings().apply();
+        }
+    }
+
+    Ok(results)
+}
@@ -1,6 +1,22 @@
+pub mod alias;
 pub mod configration;
+pub mod discovery;
 pub mod group;
 pub mod handle;
+pub mod health;
+pub mod integrity;
+pub mod launcher;
+pub mod manifest;
+pub mod mirror;
 pub mod node;
+pub mod packages;
 pub mod project;
+pub mod prune;
+pub mod required;
+pub mod runner;
+pub mod scanner;
+pub mod security;
 pub mod tray;
+pub mod usage;
+pub mod version_file;
+pub mod watcher;
@@ -1,5 +1,4 @@
 use std::{
-    cmp::Ordering,
     path::PathBuf,
     sync::{Arc, Mutex},
     time::Duration,
@@ -7,20 +6,24 @@ use std::{
 
 use anyhow::{anyhow, bail, Context, Result};
 use get_node::{
-    archive::{fetch_native, FetchConfig},
+    archive::{fetch_native, FetchConfig, FetchResult},
     list::{version_list, ListConfig},
 };
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use tauri::Emitter;
 use tokio::{sync::watch, time::Instant};
-use version_compare::{compare, Cmp};
 
 use crate::{
     config::{Config, NVersion},
-    core::handle,
+    core::{
+        alias, group, handle, integrity,
+        manifest::{self, Manifest},
+        packages, project, security,
+        usage::{self, Usage, UsageKind},
+    },
     log_err,
-    utils::dirs,
+    utils::{dirs, help},
 };
 
 static CANCEL_SENDER: Lazy<Arc<Mutex<Option<watch::Sender<bool>>>>> =
@@ -48,12 +51,16 @@ pub fn get_current(fetch: Option<bool>) -> Result<Option<String>> {
 }
 
 /// Set the current node version
+/// an alias is resolved & remembered, so repointing it updates the global version
 pub async fn set_current(version: Option<String>) -> Result<()> {
     let version = version.as_deref().unwrap_or("");
+    let name = alias::find_alias(version);
+    let version = alias::resolve_version(version)?;
 
-    Config::node().draft().update_current(version)?;
+    Config::node().draft().update_current(&version)?;
     Config::node().apply();
     Config::node().data().save_current()?;
+    alias::update_default(name)?;
 
     log_err!(handle::Handle::update_systray_part());
 
@@ -62,6 +69,8 @@ pub async fn set_current(version: Option<String>) -> Result<()> {
 
 /// update current from menu
 pub async fn update_current_from_menu(current: String) -> Result<()> {
+    let name = alias::find_alias(&current);
+    let current = alias::resolve_version(&current)?;
     let ret = {
         Config::node().draft().update_current(&current)?;
         log_err!(handle::Handle::update_systray_part_with_emit(
@@ -75,6 +84,7 @@ pub async fn update_current_from_menu(current: String) -> Result<()> {
         Ok(()) => {
             Config::node().apply();
             Config::node().data().save_current()?;
+            alias::update_default(name)?;
 
             Ok(())
         }
@@ -110,6 +120,9 @@ pub async fn get_version_list(fetch: Option<bool>) -> Result<Option<Vec<NVersion
     Config::node().draft().update_list(&list)?;
     Config::node().apply();
 
+    // notify the security releases of the versions in use
+    log_err!(security::notify_security_alerts().await);
+
     Ok(Some(list))
 }
 
@@ -131,10 +144,24 @@ pub async fn get_installed_list(fetch: Option<bool>) -> Result<Option<Vec<String
 
     let list = Config::node().latest().get_installed().unwrap_or_default();
 
+    // link the versions of read-only sources into the install directory
+    let sources = Config::settings()
+        .latest()
+        .get_sources()
+        .unwrap_or_default();
+    let readonly = {
+        let directory = directory.to_string_lossy().to_string();
+        tokio::task::spawn_blocking(move || help::link_sources(&directory, &sources)).await?
+    };
+
     let mut versions = vec![];
     let mut entries = tokio::fs::read_dir(&directory).await?;
     while let Some(entry) = entries.next_entry().await? {
         let version = entry.file_name().to_string_lossy().to_string();
+        // skip the temporary directories
+        if version.starts_with('.') {
+            continue;
+        }
         let node_path = directory.clone();
         #[cfg(target_os = "windows")]
         let node_path = node_path.join(&version).join("node.exe");
@@ -144,19 +171,21 @@ pub async fn get_installed_list(fetch: Option<bool>) -> Result<Option<Vec<String
             versions.push(version);
         }
     }
-    versions.sort_by(|a, b| match compare(b, a) {
-        Ok(Cmp::Lt) => Ordering::Less,
-        Ok(Cmp::Eq) => Ordering::Equal,
-        Ok(Cmp::Gt) => Ordering::Greater,
-        _ => unreachable!(),
-    });
+    versions.sort_by(|a, b| help::compare_installed(a, b));
 
     // update installed
     Config::node().draft().update_installed(&versions)?;
+    let external = {
+        let directory = directory.to_string_lossy().to_string();
+        help::external_versions(&directory, &readonly)
+    };
+    Config::node().draft().update_readonly(&readonly)?;
+    Config::node().draft().update_external(&external)?;
     Config::node().apply();
 
-    // update system tray
-    if list.len() != versions.len() {
+    // re-resolve the aliases & update system tray
+    if list != versions {
+        log_err!(alias::sync_aliases().await);
         log_err!(handle::Handle::update_systray_part());
     }
 
@@ -164,10 +193,12 @@ pub async fn get_installed_list(fetch: Option<bool>) -> Result<Option<Vec<String
 }
 
 /// install node
+/// the global packages of `migrate_from` are reinstalled in the background after installation
 pub async fn install_node(
     window: tauri::Window,
     version: Option<String>,
     arch: Option<String>,
+    migrate_from: Option<String>,
 ) -> Result<String> {
     if version.is_none() {
         bail!("version should not be null");
@@ -186,6 +217,13 @@ pub async fn install_node(
         *sender_lock = Some(cancel_sender);
     }
 
+    let record = Manifest {
+        version: version.clone(),
+        arch: arch.clone(),
+        source: Some(mirror.clone()),
+        ..Manifest::default()
+    };
+
     let config = FetchConfig {
         dest: directory,
         mirror: mirror,
@@ -214,7 +252,57 @@ pub async fn install_node(
         }),
     };
 
-    fetch_native(config).await
+    let FetchResult {
+        path,
+        archive,
+        checksum,
+    } = fetch_native(config).await?;
+
+    // record the metadata of the installation
+    let record = Manifest {
+        arch: record
+            .arch
+            .or_else(|| manifest::arch_from_archive(&archive)),
+        installed_at: Some(manifest::now_millis()),
+        archive: Some(archive),
+        checksum: Some(checksum),
+        ..record
+    };
+    log_err!(manifest::write_manifest(&PathBuf::from(&path), &record).await);
+    log_err!(integrity::write_file_hashes(PathBuf::from(&path)).await);
+
+    // install the default packages & migrate the packages in the background
+    // the failures are reported without failing the installation
+    let default_packages = settings.default_packages.unwrap_or_default();
+    if !default_packages.is_empty() || migrate_from.is_some() {
+        let to = PathBuf::from(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        tauri::async_runtime::spawn(async move {
+            if !default_packages.is_empty() {
+                match packages::install_packages(to.clone(), default_packages).await {
+                    Ok(report) => {
+                        log_err!(handle::Handle::emit_to_main(
+                            "on-packages-installed",
+                            report
+                        ))
+                    }
+                    Err(err) => log::error!(target: "app", "{err}"),
+                }
+            }
+            if let Some(from) = migrate_from {
+                match packages::migrate_packages(from, to, None).await {
+                    Ok(report) => {
+                        log_err!(handle::Handle::emit_to_main("on-packages-migrated", report))
+                    }
+                    Err(err) => log::error!(target: "app", "{err}"),
+                }
+            }
+        });
+    }
+
+    Ok(path)
 }
 
 /// cancel install node
@@ -229,11 +317,65 @@ pub async fn install_node_cancel() -> Result<()> {
     Ok(())
 }
 
+/// whether the running installation was cancelled
+pub fn is_install_cancelled() -> bool {
+    CANCEL_SENDER
+        .lock()
+        .ok()
+        .and_then(|sender| sender.as_ref().map(|sender| *sender.borrow()))
+        .unwrap_or(false)
+}
+
 /// uninstall node
-pub async fn uninstall_node(version: String, current: Option<bool>) -> Result<()> {
+/// a version in use is kept unless `force` is `true`
+/// the dependents are switched to `reassign` when it is set
+/// return the dependents of the version
+pub async fn uninstall_node(
+    version: String,
+    current: Option<bool>,
+    force: Option<bool>,
+    reassign: Option<String>,
+) -> Result<Vec<Usage>> {
+    let readonly = { Config::node().latest().get_readonly() }.unwrap_or_default();
+    if readonly.contains(&version) {
+        bail!("the version \"{version}\" is from a read-only source and cannot be uninstalled");
+    }
+
+    let usages = usage::version_usages(&version);
+    if !usages.is_empty() && !force.unwrap_or(false) {
+        let names = usages
+            .iter()
+            .map(|usage| usage.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        bail!("the version \"{version}\" is still used by: {names}");
+    }
+    // switch the dependents before removing the version, nothing is removed when it fails
+    if let Some(reassign) = &reassign {
+        let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+        let reassign_dir = PathBuf::from(
+            Config::settings()
+                .latest()
+                .get_directory()
+                .unwrap_or_default(),
+        )
+        .join(reassign);
+        if reassign == &version
+            || !installed.contains(reassign)
+            || !dirs::node_exe_path(&reassign_dir).exists()
+        {
+            bail!("the version \"{reassign}\" to reassign is not installed");
+        }
+        for item in usages.iter().filter(|item| item.kind == UsageKind::Alias) {
+            alias::validate(&item.name, reassign)?;
+        }
+        reassign_usages(&usages, reassign).await?;
+    }
+
     let directory = Config::settings().latest().get_directory();
     if let Some(directory) = directory {
-        let current = current.unwrap_or(false);
+        let current = current.unwrap_or(false)
+            || Config::node().latest().get_current().as_deref() == Some(&version);
         let directory = PathBuf::from(directory).join(&version);
 
         let remove_version = async {
@@ -243,14 +385,12 @@ pub async fn uninstall_node(version: String, current: Option<bool>) -> Result<()
             ))
         };
         let remove_current = async {
-            if current {
-                let default_path = dirs::default_version_path()?;
-                tokio::fs::remove_dir_all(&default_path)
-                    .await
-                    .context(format!(
-                        "Failed to remove the default file: {:?}",
-                        default_path
-                    ))
+            let default_path = dirs::default_version_path()?;
+            if current && reassign.is_none() && default_path.exists() {
+                tokio::fs::remove_file(&default_path).await.context(format!(
+                    "Failed to remove the default file: {:?}",
+                    default_path
+                ))
             } else {
                 Ok(())
             }
@@ -259,7 +399,46 @@ pub async fn uninstall_node(version: String, current: Option<bool>) -> Result<()
         let (r_version, r_current) = tokio::join!(remove_version, remove_current);
         r_version?;
         r_current?;
+
+        if current && reassign.is_none() {
+            Config::node().draft().update_current("")?;
+            Config::node().apply();
+        }
     }
 
-    Ok(())
+    // update installed, aliases & system tray
+    get_installed_list(Some(true)).await?;
+
+    Ok(usages)
+}
+
+/// switch the dependents to another version
+async fn reassign_usages(usages: &[Usage], version: &str) -> Result<()> {
+    for item in usages {
+        match item.kind {
+            UsageKind::Default => set_current(Some(version.to_string())).await?,
+            UsageKind::Alias => alias::set_alias(item.name.clone(), version.to_string()).await?,
+            UsageKind::Group => {
+                let update =
+                    group::update_group_version(item.name.clone(), version.to_string()).await?;
+                if !update.applied {
+                    bail!(
+                        "failed to update the projects of the group \"{}\"",
+                        item.name
+                    );
+                }
+            }
+            UsageKind::Project => {
+                let id = item.id.as_deref().unwrap_or_default();
+                Config::projects().draft().update_version(id, version)?;
+                Config::projects().apply();
+                Config::projects().data().save_file()?;
+                if let Some(path) = &item.path {
+                    project::sync_project_version(PathBuf::from(path), version).await?;
+                }
+            }
+        }
+    }
+
+    handle::Handle::update_systray_part_with_emit("call-projects-update", version)
 }
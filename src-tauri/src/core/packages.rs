@@ -0,0 +1,334 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::Config,
+    log_err,
+    utils::{dirs, help},
+};
+
+use super::handle;
+
+/// the packages bundled with node
+const BUNDLED_PACKAGES: [&str; 2] = ["npm", "corepack"];
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalPackage {
+    /// package name
+    pub name: String,
+
+    /// package version
+    pub version: Option<String>,
+
+    /// whether the package is linked by `npm link`
+    pub linked: bool,
+
+    /// the executables provided by the package
+    pub bins: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageResult {
+    /// package name
+    pub name: String,
+
+    /// package version
+    pub version: Option<String>,
+
+    /// whether the package was skipped (already installed or linked)
+    pub skipped: bool,
+
+    /// error message when the installation failed
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageProgress<'a> {
+    /// the source version when migrating
+    pub from: Option<&'a str>,
+
+    /// the target version
+    pub to: &'a str,
+
+    /// the package being installed
+    pub name: &'a str,
+
+    /// the index of the package (from 1)
+    pub current: usize,
+
+    /// packages count
+    pub total: usize,
+}
+
+/// get the version directory in the install directory
+fn version_dir(version: &str) -> PathBuf {
+    let directory = Config::settings()
+        .latest()
+        .get_directory()
+        .unwrap_or_default();
+    PathBuf::from(directory).join(version)
+}
+
+/// get the version directory whose global packages can be changed
+fn npm_version_dir(version: &str) -> Result<PathBuf> {
+    let readonly = { Config::node().latest().get_readonly() }.unwrap_or_default();
+    if readonly.iter().any(|item| item == version) {
+        bail!("the version \"{version}\" is from a read-only source");
+    }
+    let external = { Config::node().latest().get_external() }.unwrap_or_default();
+    if external.iter().any(|item| item == version) {
+        bail!("the version \"{version}\" is registered from another version manager");
+    }
+
+    let version_dir = version_dir(version);
+    if !dirs::npm_exe_path(&version_dir).exists() {
+        bail!("the npm executable of the version \"{version}\" is missing");
+    }
+    Ok(version_dir)
+}
+
+/// read the package in the `node_modules` dir
+async fn read_package(path: &Path, name: String) -> GlobalPackage {
+    let linked = tokio::fs::symlink_metadata(path)
+        .await
+        .map(|metadata| metadata.file_type().is_symlink())
+        .unwrap_or(false);
+    let package = help::async_read_json::<serde_json::Value>(&path.join("package.json"))
+        .await
+        .unwrap_or_default();
+    let version = package["version"].as_str().map(|v| v.to_string());
+    let bins = package_bins(&name, &package["bin"]);
+
+    GlobalPackage {
+        name,
+        version,
+        linked,
+        bins,
+    }
+}
+
+/// get the executable names from the `bin` field of `package.json`
+/// a string `bin` is named after the package (without scope)
+fn package_bins(name: &str, bin: &serde_json::Value) -> Vec<String> {
+    match bin {
+        serde_json::Value::String(_) => {
+            vec![name.rsplit('/').next().unwrap_or(name).to_string()]
+        }
+        serde_json::Value::Object(bins) => bins.keys().cloned().collect(),
+        _ => vec![],
+    }
+}
+
+/// read the global packages of the version directory, including the scoped packages
+pub async fn read_global_packages(version_dir: &Path) -> Result<Vec<GlobalPackage>> {
+    let node_modules = dirs::node_modules_dir(version_dir);
+    if !node_modules.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut packages = vec![];
+    let mut entries = tokio::fs::read_dir(&node_modules).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        if !name.starts_with('@') {
+            packages.push(read_package(&entry.path(), name).await);
+            continue;
+        }
+
+        let mut scoped = tokio::fs::read_dir(entry.path()).await?;
+        while let Some(scoped_entry) = scoped.next_entry().await? {
+            let scoped_name = scoped_entry.file_name().to_string_lossy().to_string();
+            let full_name = format!("{name}/{scoped_name}");
+            packages.push(read_package(&scoped_entry.path(), full_name).await);
+        }
+    }
+    packages.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(packages)
+}
+
+/// run the npm command of the version directory
+/// the last line of stderr is returned as the error
+async fn run_npm(version_dir: &Path, args: &[&str]) -> Result<()> {
+    let output = help::npm_command(version_dir)?.args(args).output().await?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let message = stderr
+            .lines()
+            .rfind(|line| !line.trim().is_empty())
+            .unwrap_or("npm command failed");
+        bail!("{message}");
+    }
+    Ok(())
+}
+
+/// install the global package with the npm of the version directory
+async fn install_package(version_dir: &Path, spec: &str) -> Result<()> {
+    run_npm(version_dir, &["install", "--global", spec]).await
+}
+
+/// get the global packages of the installed version
+pub async fn global_packages(version: String) -> Result<Vec<GlobalPackage>> {
+    let version_dir = version_dir(&version);
+    if !version_dir.exists() {
+        bail!("the version \"{version}\" is not installed");
+    }
+    read_global_packages(&version_dir).await
+}
+
+/// uninstall the global package with the npm of the version
+pub async fn uninstall_package(version: String, name: String) -> Result<()> {
+    let version_dir = npm_version_dir(&version)?;
+    run_npm(&version_dir, &["uninstall", "--global", &name]).await
+}
+
+/// update the global package with the npm of the version
+/// range: the version range to install, `latest` when `None`
+pub async fn update_package(version: String, name: String, range: Option<String>) -> Result<()> {
+    let version_dir = npm_version_dir(&version)?;
+    let range = range.unwrap_or_else(|| "latest".to_string());
+    install_package(&version_dir, &format!("{name}@{range}")).await
+}
+
+/// split the package spec into name & version range
+/// e.g. `@scope/name@^1.0.0` => (`@scope/name`, Some(`^1.0.0`))
+fn split_spec(spec: &str) -> (&str, Option<&str>) {
+    match spec.rfind('@') {
+        Some(index) if index > 0 => (&spec[..index], Some(&spec[index + 1..])),
+        _ => (spec, None),
+    }
+}
+
+/// install the global packages into the version
+/// specs: package names with optional version ranges, e.g. `pnpm@^8`
+pub async fn install_packages(version: String, specs: Vec<String>) -> Result<Vec<PackageResult>> {
+    let to_dir = npm_version_dir(&version)?;
+
+    let total = specs.len();
+    let mut results = vec![];
+    for (index, spec) in specs.iter().enumerate() {
+        let (name, range) = split_spec(spec.trim());
+        log_err!(handle::Handle::emit_to_main(
+            "on-packages-progress",
+            PackageProgress {
+                from: None,
+                to: &version,
+                name,
+                current: index + 1,
+                total,
+            }
+        ));
+
+        let error = install_package(&to_dir, spec.trim())
+            .await
+            .err()
+            .map(|err| err.to_string());
+        results.push(PackageResult {
+            name: name.to_string(),
+            version: range.map(|range| range.to_string()),
+            skipped: false,
+            error,
+        });
+    }
+
+    Ok(results)
+}
+
+/// reinstall the global packages of the source version into the target version
+/// packages: the package names to migrate, all when `None`
+pub async fn migrate_packages(
+    from: String,
+    to: String,
+    packages: Option<Vec<String>>,
+) -> Result<Vec<PackageResult>> {
+    let from_dir = version_dir(&from);
+    if !from_dir.exists() {
+        bail!("the version \"{from}\" is not installed");
+    }
+    let to_dir = npm_version_dir(&to)?;
+
+    let installed = read_global_packages(&to_dir).await?;
+    let source = read_global_packages(&from_dir)
+        .await?
+        .into_iter()
+        .filter(|package| !BUNDLED_PACKAGES.contains(&package.name.as_str()))
+        .filter(|package| {
+            packages
+                .as_ref()
+                .is_none_or(|names| names.contains(&package.name))
+        })
+        .collect::<Vec<_>>();
+
+    let total = source.len();
+    let mut results = vec![];
+    for (index, package) in source.into_iter().enumerate() {
+        log_err!(handle::Handle::emit_to_main(
+            "on-packages-progress",
+            PackageProgress {
+                from: Some(&from),
+                to: &to,
+                name: &package.name,
+                current: index + 1,
+                total,
+            }
+        ));
+
+        let exists = installed
+            .iter()
+            .any(|item| item.name == package.name && item.version == package.version);
+        let (skipped, error) = if package.linked {
+            (
+                true,
+                Some("linked package, run `npm link` again".to_string()),
+            )
+        } else if exists {
+            (true, None)
+        } else {
+            let spec = match &package.version {
+                Some(version) => format!("{}@{}", package.name, version),
+                None => package.name.clone(),
+            };
+            let error = install_package(&to_dir, &spec)
+                .await
+                .err()
+                .map(|err| err.to_string());
+            (false, error)
+        };
+
+        results.push(PackageResult {
+            name: package.name,
+            version: package.version,
+            skipped,
+            error,
+        });
+    }
+
+    Ok(results)
+}
+
+#[test]
+fn test_split_spec() {
+    assert_eq!(split_spec("typescript"), ("typescript", None));
+    assert_eq!(split_spec("pnpm@^8"), ("pnpm", Some("^8")));
+    assert_eq!(split_spec("@vue/cli"), ("@vue/cli", None));
+    assert_eq!(split_spec("@vue/cli@5.0.8"), ("@vue/cli", Some("5.0.8")));
+}
+
+#[test]
+fn test_package_bins() {
+    let bin = serde_json::json!("./bin/cli.js");
+    assert_eq!(package_bins("@vue/cli", &bin), vec!["cli".to_string()]);
+
+    let bin = serde_json::json!({ "tsc": "./bin/tsc", "tsserver": "./bin/tsserver" });
+    assert_eq!(package_bins("typescript", &bin).len(), 2);
+
+    assert!(package_bins("lodash", &serde_json::Value::Null).is_empty());
+}
@@ -1,16 +1,19 @@
-use std::path::PathBuf;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use crate::{
     config::{Config, Project},
     log_err,
     utils::{dirs, help},
 };
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use futures::{stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use tauri_plugin_dialog::{DialogExt, FilePath};
 
-use super::handle;
+use super::{alias, handle, version_file};
 
 /// get project list from `projects.json`
 pub async fn project_list(fetch: Option<bool>) -> Result<Option<Vec<Project>>> {
@@ -34,8 +37,15 @@ pub struct PInfo {
     /// project floder path
     pub path: PathBuf,
 
-    /// project version from `.nvmdrc`
+    /// the proposed project version detected from the version files
     pub version: Option<String>,
+
+    /// the version file used
+    /// `.nvmdrc`, `.nvmrc`, `.node-version`, `package.json#volta` or `package.json#engines`
+    pub source: Option<String>,
+
+    /// the version or range as written in the version file
+    pub spec: Option<String>,
 }
 
 /// add projects
@@ -44,13 +54,13 @@ pub async fn select_projects(app_handle: tauri::AppHandle) -> Result<Option<Vec<
         let mut p_info = Vec::new();
         for file_path in file_paths {
             if let FilePath::Path(path) = file_path {
-                let nvmdrc_path = path.join(".nvmdrc");
-                let version = if nvmdrc_path.exists() {
-                    Some(help::async_read_string(&nvmdrc_path).await?)
-                } else {
-                    None
-                };
-                p_info.push(PInfo { path, version });
+                let detected = version_file::detect_version(&path).await?;
+                p_info.push(PInfo {
+                    path,
+                    version: detected.as_ref().and_then(|d| d.version.clone()),
+                    source: detected.as_ref().map(|d| d.source.clone()),
+                    spec: detected.map(|d| d.spec),
+                });
             }
         }
         Ok(Some(p_info))
@@ -59,10 +69,168 @@ pub async fn select_projects(app_handle: tauri::AppHandle) -> Result<Option<Vec<
     }
 }
 
+/// resolve the node version the project uses, through its group & alias
+/// the version files are read for the projects not added, the global version is used when not set
+pub async fn resolve_project_version(path: &Path) -> Result<String> {
+    let projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+    let version = match projects
+        .into_iter()
+        .find(|project| Path::new(&project.path) == path)
+    {
+        Some(project) => project.version.filter(|version| !version.is_empty()),
+        None => version_file::detect_version(path)
+            .await?
+            .and_then(|detected| detected.version),
+    };
+
+    let groups = { Config::groups().latest().get_list() }.unwrap_or_default();
+    let version = match version {
+        Some(version) => match groups.into_iter().find(|group| group.name == version) {
+            Some(group) => group.version.unwrap_or_default(),
+            None => version,
+        },
+        None => { Config::node().latest().get_current() }.unwrap_or_default(),
+    };
+    if version.is_empty() {
+        bail!(
+            "no node version is set for the project \"{}\"",
+            path.display()
+        );
+    }
+
+    let version = alias::resolve_version(&version)?;
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+    if !installed.contains(&version) {
+        bail!("the version \"{version}\" used by the project is not installed");
+    }
+    Ok(version)
+}
+
+/// get the installation directory of the node version the project uses
+pub async fn project_version_dir(path: &Path) -> Result<PathBuf> {
+    let version = resolve_project_version(path).await?;
+    let directory = Config::settings()
+        .latest()
+        .get_directory()
+        .unwrap_or_default();
+    Ok(PathBuf::from(directory).join(version))
+}
+
+/// update the version files of the project in the draft & save it, the draft is applied by the caller
+fn save_version_files(id: &str, files: Option<Vec<String>>) -> Result<Project> {
+    let project = Config::projects().draft().update_version_files(id, files)?;
+    Config::projects().draft().save_file()?;
+    Ok(project)
+}
+
+/// update the version files maintained for the project & write the project version into them
+/// files: `None` to follow the global setting
+pub async fn update_project_version_files(id: String, files: Option<Vec<String>>) -> Result<()> {
+    let project = match save_version_files(&id, files) {
+        Ok(project) => project,
+        Err(err) => {
+            Config::projects().discard();
+            return Err(err);
+        }
+    };
+    Config::projects().apply();
+
+    let path = PathBuf::from(&project.path);
+    if project.version.is_some() && path.exists() {
+        let version = resolve_project_version(&path).await?;
+        sync_project_version(path, &version).await?;
+    }
+
+    Ok(())
+}
+
+/// the file the project environment variables are mirrored into for the shim
+const ENV_FILE: &str = ".nvmdenv";
+
+/// get the environment variables of the project, empty for the projects not added
+pub fn project_env(path: &Path) -> BTreeMap<String, String> {
+    { Config::projects().latest().get_list() }
+        .unwrap_or_default()
+        .into_iter()
+        .find(|project| Path::new(&project.path) == path)
+        .and_then(|project| project.env)
+        .unwrap_or_default()
+}
+
+/// check the environment variables, `PATH` is managed by the app
+fn validate_env(env: &BTreeMap<String, String>) -> Result<()> {
+    for (key, value) in env {
+        if key.is_empty() || key.contains(['=', '\0']) || key.chars().any(char::is_whitespace) {
+            bail!("the environment variable name \"{key}\" is invalid");
+        }
+        if key.eq_ignore_ascii_case("PATH") {
+            bail!("the environment variable \"{key}\" is managed by the app");
+        }
+        if value.contains('\0') {
+            bail!("the value of the environment variable \"{key}\" is invalid");
+        }
+    }
+    Ok(())
+}
+
+/// format the environment variables as `KEY="value"` lines, the values are quoted as json strings
+fn env_file_content(env: &BTreeMap<String, String>) -> String {
+    env.iter()
+        .map(|(key, value)| format!("{key}={}\n", serde_json::Value::String(value.clone())))
+        .collect()
+}
+
+/// write the environment variables into `.nvmdenv`, the file is removed when not mirrored
+async fn write_env_file(path: &Path, env: &BTreeMap<String, String>, mirror: bool) -> Result<()> {
+    let file = path.join(ENV_FILE);
+    if mirror && !env.is_empty() {
+        help::async_save_string(&file, &env_file_content(env)).await?;
+    } else if file.exists() {
+        tokio::fs::remove_file(&file).await?;
+    }
+    Ok(())
+}
+
+/// update the environment variables of the project
+pub async fn update_project_env(
+    id: String,
+    env: BTreeMap<String, String>,
+    env_file: bool,
+) -> Result<()> {
+    validate_env(&env)?;
+
+    let ret = {
+        let path = Config::projects()
+            .draft()
+            .update_env(&id, env.clone(), env_file)?;
+        let path = PathBuf::from(path);
+        if path.exists() {
+            write_env_file(&path, &env, env_file).await?;
+        }
+        <Result<()>>::Ok(())
+    };
+
+    match ret {
+        Ok(()) => {
+            Config::projects().apply();
+            Config::projects().data().save_file()?;
+            Ok(())
+        }
+        Err(err) => {
+            Config::projects().discard();
+            Err(err)
+        }
+    }
+}
+
 /// update projects
 pub async fn update_projects(list: Vec<Project>, path: Option<PathBuf>) -> Result<()> {
     if let Some(path) = path {
         tokio::fs::remove_file(&path.join(".nvmdrc")).await?;
+        // the mirrored environment variables of the removed project
+        if path.join(ENV_FILE).exists() {
+            tokio::fs::remove_file(&path.join(ENV_FILE)).await?;
+        }
     }
 
     Config::projects().draft().update_list(&list)?;
@@ -74,44 +242,145 @@ pub async fn update_projects(list: Vec<Project>, path: Option<PathBuf>) -> Resul
     Ok(())
 }
 
-/// sync project version to `.nvmdrc`
+/// sync project version to the maintained version files (`.nvmdrc` & the configured ones)
+/// an alias is written as the version it resolves to
 pub async fn sync_project_version(path: PathBuf, version: &str) -> Result<i32> {
     if !path.exists() {
         return Ok(404);
     }
 
-    let path = path.join(".nvmdrc");
-    help::async_save_string(&path, version).await?;
+    let version = alias::resolve_version(version)?;
+    let files = version_file::maintained_files(&path);
+    version_file::write_version(&path, &version, &files).await?;
 
     Ok(200)
 }
 
-/// batch update project version
-pub async fn batch_update_project_version(paths: Vec<PathBuf>, version: String) -> Result<()> {
-    let result = stream::iter(paths.into_iter())
-        .map(|path| {
-            let version = version.clone();
-            async move {
-                let path = path.join(".nvmdrc");
-                help::async_save_string(&path, &version).await
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncResult {
+    /// project floder path
+    pub path: PathBuf,
+
+    /// error message when writing the version files failed
+    pub error: Option<String>,
+
+    /// whether the project directory no longer exists, such a project is skipped
+    pub missing: bool,
+
+    /// whether the written version files were restored because of other failures
+    pub rolled_back: bool,
+}
+
+/// write the version into the version files of the projects concurrently
+/// the projects no longer existing are skipped, all the projects are restored when any of them fails
+/// return the results & the snapshots to restore the projects later (empty when restored)
+pub async fn write_versions(
+    paths: Vec<PathBuf>,
+    version: &str,
+) -> (Vec<SyncResult>, Vec<version_file::Snapshot>) {
+    let written = stream::iter(paths.into_iter())
+        .map(|path| async move {
+            if !path.exists() {
+                return (path, vec![], None, true);
             }
+            let files = version_file::maintained_files(&path);
+            let snapshots = version_file::snapshot(&path, &files).await;
+            let error = match version_file::write_version(&path, version, &files).await {
+                Ok(()) => None,
+                Err(err) => {
+                    // restore the files written partly
+                    log_err!(version_file::restore(&snapshots).await);
+                    Some(err.to_string())
+                }
+            };
+            (path, snapshots, error, false)
         })
         .buffer_unordered(3)
         .collect::<Vec<_>>()
         .await;
 
-    for ret in result {
-        ret?;
+    let failed = written.iter().any(|(_, _, error, _)| error.is_some());
+    let mut results = vec![];
+    let mut snapshots = vec![];
+    for (path, written_snapshots, error, missing) in written {
+        let rolled_back = failed && error.is_none() && !missing;
+        if rolled_back {
+            log_err!(version_file::restore(&written_snapshots).await);
+        } else if !failed {
+            snapshots.extend(written_snapshots);
+        }
+        results.push(SyncResult {
+            path,
+            error,
+            missing,
+            rolled_back,
+        });
     }
 
-    Ok(())
+    (results, snapshots)
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchUpdate {
+    /// the results of the projects
+    pub results: Vec<SyncResult>,
+
+    /// whether the update was applied, `false` when rolled back
+    pub applied: bool,
+}
+
+/// update the project versions in the draft & save it, the draft is applied by the caller only when saved
+fn save_project_versions(paths: &[String], version: &str) -> Result<()> {
+    Config::projects().draft().update_versions(paths, version)?;
+    Config::projects().draft().save_file()
+}
+
+/// batch update project version
+/// the version files & `projects.json` are updated together, nothing is changed when any project fails
+/// record: the version recorded in `projects.json` (e.g. the group name), `version` when `None`
+pub async fn batch_update_project_version(
+    paths: Vec<PathBuf>,
+    version: String,
+    record: Option<String>,
+) -> Result<BatchUpdate> {
+    let resolved = alias::resolve_version(&version)?;
+    let (results, snapshots) = write_versions(paths.clone(), &resolved).await;
+    if results.iter().any(|result| result.error.is_some()) {
+        return Ok(BatchUpdate {
+            results,
+            applied: false,
+        });
+    }
+
+    let paths = paths
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    let record = record.unwrap_or(version);
+    if let Err(err) = save_project_versions(&paths, &record) {
+        Config::projects().discard();
+        // the file may have been saved partly
+        log_err!(Config::projects().data().save_file());
+        log_err!(version_file::restore(&snapshots).await);
+        return Err(err);
+    }
+    Config::projects().apply();
+
+    log_err!(handle::Handle::update_systray_part());
+
+    Ok(BatchUpdate {
+        results,
+        applied: true,
+    })
 }
 
 /// change project with version from menu
-pub async fn change_with_version(name: String, version: String) -> Result<()> {
+pub async fn change_with_version(id: String, version: String) -> Result<()> {
     let ret = {
-        let project_path = Config::projects().draft().update_version(&name, &version)?;
-        let need_update_groups = Config::groups().draft().update_projects(&project_path)?;
+        let project_path = Config::projects().draft().update_version(&id, &version)?;
+        let need_update_groups = Config::groups().draft().update_projects(&id)?;
 
         sync_project_version(PathBuf::from(&project_path), &version).await?;
 
@@ -144,14 +413,14 @@ pub async fn change_with_version(name: String, version: String) -> Result<()> {
 }
 
 /// change project with group from menu
-pub async fn change_with_group(name: String, group_name: String) -> Result<()> {
+pub async fn change_with_group(id: String, group_name: String) -> Result<()> {
     let ret = {
         let project_path = Config::projects()
             .draft()
-            .update_version(&name, &group_name)?;
+            .update_version(&id, &group_name)?;
         let version = Config::groups()
             .draft()
-            .update_projects_version(&project_path, &group_name)?
+            .update_projects_version(&id, &group_name)?
             .ok_or_else(|| anyhow!("failed to find the group version \"name:{}\"", &group_name))?;
 
         sync_project_version(PathBuf::from(&project_path), &version).await?;
@@ -181,3 +450,28 @@ pub async fn change_with_group(name: String, group_name: String) -> Result<()> {
         }
     }
 }
+
+#[test]
+fn test_project_env() {
+    let env = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    let valid = env(&[
+        ("NODE_OPTIONS", "--max-old-space-size=8192"),
+        ("NODE_EXTRA_CA_CERTS", "C:\\certs\\ca \"root\".pem"),
+    ]);
+    assert!(validate_env(&valid).is_ok());
+    assert_eq!(
+        env_file_content(&valid),
+        "NODE_EXTRA_CA_CERTS=\"C:\\\\certs\\\\ca \\\"root\\\".pem\"\nNODE_OPTIONS=\"--max-old-space-size=8192\"\n"
+    );
+
+    assert!(validate_env(&env(&[("", "value")])).is_err());
+    assert!(validate_env(&env(&[("A=B", "value")])).is_err());
+    assert!(validate_env(&env(&[("MY VAR", "value")])).is_err());
+    assert!(validate_env(&env(&[("Path", "/usr/bin")])).is_err());
+}
@@ -0,0 +1,209 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Config, utils::help};
+
+use super::{manifest, node, usage};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneOptions {
+    /// remove every version not used by the default, projects, groups or aliases
+    pub unused: Option<bool>,
+
+    /// keep only the newest N versions of each major line, the versions in use are always kept
+    pub keep_latest: Option<usize>,
+
+    /// only report the versions to remove
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PruneReason {
+    /// not used anywhere
+    Unused,
+
+    /// older than the newest N versions of the major line
+    Retention,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneCandidate {
+    /// node version
+    pub version: String,
+
+    /// why the version is removed
+    pub reason: PruneReason,
+
+    /// size on disk (bytes)
+    pub size: u64,
+
+    /// error message when the removal failed
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneReport {
+    /// the versions removed (or to remove when dry run)
+    pub candidates: Vec<PruneCandidate>,
+
+    /// the reclaimable disk space (bytes)
+    pub reclaimable: u64,
+
+    /// whether nothing was removed
+    pub dry_run: bool,
+}
+
+/// get the major part of the version
+fn major(version: &str) -> &str {
+    version.split('.').next().unwrap_or_default()
+}
+
+/// select the versions to remove
+/// installed: sorted from the newest, protected: the versions in use
+/// the arch builds of a version (`{version}-{arch}`) count as the version for the retention
+fn select_candidates(
+    installed: &[String],
+    protected: &[String],
+    unused: bool,
+    keep_latest: Option<usize>,
+) -> Vec<(String, PruneReason)> {
+    let mut candidates = vec![];
+    // the versions seen of each major line, from the newest
+    let mut majors: Vec<(&str, Vec<&str>)> = vec![];
+
+    for version in installed {
+        let plain = help::split_install_name(version).0;
+        let major = major(plain);
+        let versions = match majors.iter_mut().find(|(m, _)| *m == major) {
+            Some((_, versions)) => versions,
+            None => {
+                majors.push((major, vec![]));
+                &mut majors.last_mut().unwrap().1
+            }
+        };
+        let position = match versions.iter().position(|item| *item == plain) {
+            Some(index) => index + 1,
+            None => {
+                versions.push(plain);
+                versions.len()
+            }
+        };
+
+        if protected.contains(version) {
+            continue;
+        }
+        if keep_latest.is_some_and(|keep| position > keep) {
+            candidates.push((version.clone(), PruneReason::Retention));
+        } else if unused {
+            candidates.push((version.clone(), PruneReason::Unused));
+        }
+    }
+
+    candidates
+}
+
+/// remove the unused versions & the versions beyond the retention policy
+pub async fn prune_versions(options: PruneOptions) -> Result<PruneReport> {
+    let dry_run = options.dry_run.unwrap_or(false);
+    let directory = Config::settings()
+        .latest()
+        .get_directory()
+        .unwrap_or_default();
+    let directory = PathBuf::from(directory);
+
+    let installed = node::get_installed_list(Some(true))
+        .await?
+        .unwrap_or_default();
+    let mut protected = usage::collect_usages()
+        .into_iter()
+        .map(|usage| usage.version)
+        .collect::<Vec<_>>();
+    // the read-only versions can not be removed
+    protected.extend({ Config::node().latest().get_readonly() }.unwrap_or_default());
+
+    let selected = select_candidates(
+        &installed,
+        &protected,
+        options.unused.unwrap_or(false),
+        options.keep_latest,
+    );
+
+    let mut candidates = vec![];
+    for (version, reason) in selected {
+        let version_dir = directory.join(&version);
+        let size = {
+            let version_dir = version_dir.clone();
+            tokio::task::spawn_blocking(move || manifest::dir_size(&version_dir)).await?
+        };
+        let error = if dry_run {
+            None
+        } else {
+            tokio::fs::remove_dir_all(&version_dir)
+                .await
+                .err()
+                .map(|err| err.to_string())
+        };
+        candidates.push(PruneCandidate {
+            version,
+            reason,
+            size,
+            error,
+        });
+    }
+
+    if !dry_run && !candidates.is_empty() {
+        // update installed, aliases & system tray
+        node::get_installed_list(Some(true)).await?;
+    }
+
+    let reclaimable = candidates
+        .iter()
+        .filter(|candidate| candidate.error.is_none())
+        .map(|candidate| candidate.size)
+        .sum();
+
+    Ok(PruneReport {
+        candidates,
+        reclaimable,
+        dry_run,
+    })
+}
+
+#[test]
+fn test_select_candidates() {
+    let installed = [
+        "20.11.1", "20.11.0", "20.10.0", "18.19.0", "18.18.2", "16.20.2",
+    ]
+    .map(String::from)
+    .to_vec();
+    let protected = vec!["20.10.0".to_string()];
+
+    let candidates = select_candidates(&installed, &protected, false, Some(1));
+    assert_eq!(
+        candidates,
+        vec![
+            ("20.11.0".to_string(), PruneReason::Retention),
+            ("18.18.2".to_string(), PruneReason::Retention),
+        ]
+    );
+
+    let candidates = select_candidates(&installed, &protected, true, Some(2));
+    assert_eq!(candidates.len(), 5);
+    assert_eq!(candidates[2], ("18.19.0".to_string(), PruneReason::Unused));
+
+    // the arch builds share the slot of their version
+    let installed = ["20.11.1", "20.11.1-x64", "20.11.0", "20.10.0"]
+        .map(String::from)
+        .to_vec();
+    let candidates = select_candidates(&installed, &[], false, Some(2));
+    assert_eq!(
+        candidates,
+        vec![("20.10.0".to_string(), PruneReason::Retention)]
+    );
+}
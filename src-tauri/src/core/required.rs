@@ -0,0 +1,211 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use version_compare::{compare, Cmp};
+
+use crate::{
+    config::{Config, NVersion},
+    log_err,
+    utils::help,
+};
+
+use super::{
+    alias, handle, node,
+    usage::{self, Usage, UsageKind},
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingVersion {
+    /// the installation name, `{version}` or `{version}-{arch}`
+    pub name: String,
+
+    /// the default, projects, groups & aliases using the version
+    pub usages: Vec<Usage>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequiredInstall {
+    /// the installation name
+    pub name: String,
+
+    /// the installation path
+    pub path: Option<String>,
+
+    /// error message when the installation failed
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequiredProgress<'a> {
+    /// the installation name being installed
+    pub name: &'a str,
+
+    /// the index of the version (from 1)
+    pub current: usize,
+
+    /// versions count
+    pub total: usize,
+}
+
+/// whether the installation name is an exact version which can be installed
+fn is_exact(name: &str) -> bool {
+    let parts = help::split_install_name(name)
+        .0
+        .split('.')
+        .collect::<Vec<_>>();
+    parts.len() == 3
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// group the usages by the versions not installed
+/// an alias matching no installed version is missing its target prefix, e.g. `16`
+fn group_missing(usages: Vec<Usage>, installed: &[String]) -> Vec<MissingVersion> {
+    let mut missing: Vec<MissingVersion> = vec![];
+    for usage in usages {
+        let name = usage.version.trim_start_matches('v').to_string();
+        if installed.contains(&name) || !(is_exact(&name) || usage.kind == UsageKind::Alias) {
+            continue;
+        }
+        match missing.iter_mut().find(|item| item.name == name) {
+            Some(item) => item.usages.push(usage),
+            None => missing.push(MissingVersion {
+                name,
+                usages: vec![usage],
+            }),
+        }
+    }
+    missing.sort_by(|a, b| help::compare_installed(&a.name, &b.name));
+    missing
+}
+
+/// get the newest release matching the alias target prefix
+fn newest_release(prefix: &str, list: &[NVersion]) -> Option<String> {
+    list.iter()
+        .map(|item| item.version.trim_start_matches('v'))
+        .filter(|version| alias::matches_prefix(version, prefix))
+        .fold(None::<&str>, |newest, version| match newest {
+            Some(newest) if !matches!(compare(version, newest), Ok(Cmp::Gt)) => Some(newest),
+            _ => Some(version),
+        })
+        .map(|version| version.to_string())
+}
+
+/// get the versions used by the default, projects, groups & aliases but not installed
+pub fn missing_versions() -> Vec<MissingVersion> {
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+    group_missing(usage::collect_usages(), &installed)
+}
+
+/// install the missing versions one by one
+/// names: the installation names to install, all the missing versions when `None`
+/// the queue progress is emitted by `on-required-progress`, the download progress by `on-node-progress`
+/// the queue stops when the installation is cancelled
+pub async fn install_missing(
+    window: tauri::Window,
+    names: Option<Vec<String>>,
+) -> Result<Vec<RequiredInstall>> {
+    let missing = missing_versions()
+        .into_iter()
+        .map(|item| item.name)
+        .filter(|name| names.as_ref().is_none_or(|names| names.contains(name)))
+        .collect::<Vec<_>>();
+
+    let list = { Config::node().latest().get_list() }.unwrap_or_default();
+    let total = missing.len();
+    let mut results = vec![];
+    for (index, name) in missing.iter().enumerate() {
+        log_err!(handle::Handle::emit_to_main(
+            "on-required-progress",
+            RequiredProgress {
+                name,
+                current: index + 1,
+                total,
+            }
+        ));
+
+        let (version, arch) = help::split_install_name(name);
+        // the alias target prefix installs its newest release
+        let version = match is_exact(version) {
+            true => Some(version.to_string()),
+            false => newest_release(version, &list),
+        };
+        let ret = match version {
+            Some(version) => {
+                node::install_node(
+                    window.clone(),
+                    Some(version),
+                    arch.map(|arch| arch.to_string()),
+                    None,
+                )
+                .await
+            }
+            None => Err(anyhow!("no release matches the version \"{name}\"")),
+        };
+        let cancelled = ret.is_err() && node::is_install_cancelled();
+        results.push(match ret {
+            Ok(path) => RequiredInstall {
+                name: name.clone(),
+                path: Some(path),
+                error: None,
+            },
+            Err(err) => RequiredInstall {
+                name: name.clone(),
+                path: None,
+                error: Some(err.to_string()),
+            },
+        });
+        if cancelled {
+            break;
+        }
+    }
+
+    if results.iter().any(|result| result.error.is_none()) {
+        // update installed, aliases & system tray
+        node::get_installed_list(Some(true)).await?;
+    }
+
+    Ok(results)
+}
+
+#[test]
+fn test_group_missing() {
+    let usage = |version: &str, kind, name: &str| Usage {
+        version: version.to_string(),
+        kind,
+        name: name.to_string(),
+        id: None,
+        path: None,
+    };
+    let usages = vec![
+        usage("20.11.1", UsageKind::Default, "global"),
+        usage("18.19.0", UsageKind::Group, "web"),
+        usage("v18.19.0", UsageKind::Project, "api"),
+        usage("16.20.2-x64", UsageKind::Project, "legacy"),
+        usage("14", UsageKind::Project, "broken"),
+        usage("12", UsageKind::Alias, "old"),
+    ];
+    let installed = ["20.11.1"].map(String::from);
+
+    let missing = group_missing(usages, &installed);
+    assert_eq!(missing.len(), 3);
+    assert_eq!(missing[0].name, "18.19.0");
+    assert_eq!(missing[0].usages.len(), 2);
+    assert_eq!(missing[1].name, "16.20.2-x64");
+    assert_eq!(missing[2].name, "12");
+}
+
+#[test]
+fn test_newest_release() {
+    let release = |version: &str| NVersion {
+        version: version.to_string(),
+        ..NVersion::default()
+    };
+    let list = ["v20.11.1", "v16.20.2", "v16.9.1", "v160.0.0"].map(release);
+    assert_eq!(newest_release("16", &list), Some("16.20.2".to_string()));
+    assert_eq!(newest_release("16.9", &list), Some("16.9.1".to_string()));
+    assert_eq!(newest_release("14", &list), None);
+}
@@ -0,0 +1,440 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+    process::Stdio,
+    sync::Arc,
+};
+
+use anyhow::{anyhow, bail, Result};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+
+use crate::{
+    log_err,
+    utils::{dirs, help},
+};
+
+use super::{handle, manifest, project};
+
+/// the output lines kept for each process
+const MAX_LINES: usize = 500;
+
+/// the running & finished processes
+static PROCESSES: Lazy<Arc<Mutex<HashMap<String, Process>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Script {
+    /// script name
+    pub name: String,
+
+    /// the command of the script
+    pub command: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessStatus {
+    /// the process is running
+    Running,
+
+    /// the process exited by itself
+    Exited,
+
+    /// the process was stopped
+    Stopped,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessInfo {
+    /// process id in the app
+    pub id: String,
+
+    /// project floder path
+    pub path: PathBuf,
+
+    /// script name
+    pub script: String,
+
+    /// the node version used
+    pub version: String,
+
+    /// the system process id
+    pub pid: Option<u32>,
+
+    /// process status
+    pub status: ProcessStatus,
+
+    /// exit code
+    pub code: Option<i32>,
+
+    /// start date (milliseconds since the unix epoch)
+    pub started_at: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputLine {
+    /// process id in the app
+    pub id: String,
+
+    /// `stdout` or `stderr`
+    pub stream: String,
+
+    /// the line content
+    pub line: String,
+}
+
+struct Process {
+    info: ProcessInfo,
+    output: VecDeque<OutputLine>,
+    finished: bool,
+}
+
+/// the scripts of `package.json` in the written order
+struct Scripts(Vec<Script>);
+
+impl<'de> Deserialize<'de> for Scripts {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ScriptsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ScriptsVisitor {
+            type Value = Scripts;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("the scripts object")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut scripts = vec![];
+                while let Some((name, command)) = map.next_entry::<String, serde_json::Value>()? {
+                    scripts.push(Script {
+                        name,
+                        command: command.as_str().unwrap_or_default().to_string(),
+                    });
+                }
+                Ok(Scripts(scripts))
+            }
+        }
+
+        deserializer.deserialize_map(ScriptsVisitor)
+    }
+}
+
+#[derive(Deserialize)]
+struct Package {
+    #[serde(default)]
+    scripts: Option<Scripts>,
+}
+
+/// get the scripts of the project `package.json` in the written order
+pub async fn project_scripts(path: PathBuf) -> Result<Vec<Script>> {
+    let package = help::async_read_json::<Package>(&path.join("package.json")).await?;
+    Ok(package.scripts.map(|scripts| scripts.0).unwrap_or_default())
+}
+
+/// keep the last lines of the output
+fn push_line(output: &mut VecDeque<OutputLine>, line: OutputLine) {
+    if output.len() >= MAX_LINES {
+        output.pop_front();
+    }
+    output.push_back(line);
+}
+
+/// read the lines of the stream, save & emit them
+/// the invalid UTF-8 bytes are replaced instead of ending the stream
+async fn read_lines<R: AsyncRead + Unpin>(id: String, stream: &str, reader: R) {
+    let mut reader = BufReader::new(reader);
+    let mut buf = vec![];
+    while let Ok(size) = reader.read_until(b'\n', &mut buf).await {
+        if size == 0 {
+            break;
+        }
+        let line = String::from_utf8_lossy(&buf)
+            .trim_end_matches(['\n', '\r'])
+            .to_string();
+        buf.clear();
+        let line = OutputLine {
+            id: id.clone(),
+            stream: stream.to_string(),
+            line,
+        };
+        if let Some(process) = PROCESSES.lock().get_mut(&id) {
+            push_line(&mut process.output, line.clone());
+        }
+        log_err!(handle::Handle::emit_to_main("on-process-output", line));
+    }
+}
+
+/// spawn the script of the project with the project's node version
+async fn spawn_script(id: String, path: PathBuf, script: String) -> Result<ProcessInfo> {
+    let version_dir = project::project_version_dir(&path).await?;
+    let version = version_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let npm = dirs::npm_exe_path(&version_dir);
+    if !npm.exists() {
+        bail!("the npm executable of the version \"{version}\" is missing");
+    }
+
+    let mut command = help::async_command(npm);
+    command
+        .args(["run", &script])
+        .current_dir(&path)
+        .envs(project::project_env(&path))
+        .env(
+            "PATH",
+            help::prepend_path(&dirs::node_bin_dir(&version_dir))?,
+        )
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    // a new process group to stop the child processes together
+    #[cfg(unix)]
+    command.process_group(0);
+
+    let mut child = command.spawn()?;
+    let info = ProcessInfo {
+        id: id.clone(),
+        path,
+        script,
+        version,
+        pid: child.id(),
+        status: ProcessStatus::Running,
+        code: None,
+        started_at: manifest::now_millis(),
+    };
+    PROCESSES.lock().insert(
+        id.clone(),
+        Process {
+            info: info.clone(),
+            output: VecDeque::new(),
+            finished: false,
+        },
+    );
+    log_err!(handle::Handle::emit_to_main(
+        "on-process-update",
+        info.clone()
+    ));
+
+    let pid = info.pid;
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    tauri::async_runtime::spawn(async move {
+        let stdout = async {
+            if let Some(stdout) = stdout {
+                read_lines(id.clone(), "stdout", stdout).await;
+            }
+        };
+        let stderr = async {
+            if let Some(stderr) = stderr {
+                read_lines(id.clone(), "stderr", stderr).await;
+            }
+        };
+        let (_, _, status) = tokio::join!(stdout, stderr, child.wait());
+
+        let info = {
+            let mut processes = PROCESSES.lock();
+            // the process may be restarted with the same id
+            processes
+                .get_mut(&id)
+                .filter(|process| process.info.pid == pid)
+                .map(|process| {
+                    if process.info.status == ProcessStatus::Running {
+                        process.info.status = ProcessStatus::Exited;
+                    }
+                    process.info.code = status.ok().and_then(|status| status.code());
+                    process.finished = true;
+                    process.info.clone()
+                })
+        };
+        if let Some(info) = info {
+            log_err!(handle::Handle::emit_to_main("on-process-update", info));
+        }
+    });
+
+    Ok(info)
+}
+
+/// run the npm script of the project with the project's node version
+/// the output is emitted by `on-process-output`, the status changes by `on-process-update`
+pub async fn run_script(path: PathBuf, script: String) -> Result<ProcessInfo> {
+    let scripts = project_scripts(path.clone()).await?;
+    if !scripts.iter().any(|item| item.name == script) {
+        bail!("the script \"{script}\" is not found in package.json");
+    }
+    spawn_script(uuid::Uuid::new_v4().to_string(), path, script).await
+}
+
+/// kill the process with its child processes
+async fn kill_tree(pid: u32) -> Result<()> {
+    #[cfg(unix)]
+    let output = help::async_command("kill")
+        .args(["-TERM", &format!("-{pid}")])
+        .output()
+        .await?;
+    #[cfg(windows)]
+    let output = help::async_command("taskkill")
+        .args(["/T", "/F", "/PID", &pid.to_string()])
+        .output()
+        .await?;
+
+    if !output.status.success() {
+        bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(())
+}
+
+/// stop the running process
+pub async fn stop_process(id: String) -> Result<()> {
+    let pid = {
+        let mut processes = PROCESSES.lock();
+        let process = processes
+            .get_mut(&id)
+            .ok_or_else(|| anyhow!("failed to find the process \"id:{id}\""))?;
+        if process.info.status != ProcessStatus::Running {
+            return Ok(());
+        }
+        process.info.status = ProcessStatus::Stopped;
+        process.info.pid
+    };
+    if let Some(pid) = pid {
+        if let Err(err) = kill_tree(pid).await {
+            // the process keeps running
+            if let Some(process) = PROCESSES.lock().get_mut(&id) {
+                if !process.finished {
+                    process.info.status = ProcessStatus::Running;
+                }
+            }
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// wait until the process is not running
+/// return whether the process finished in time
+async fn wait_stopped(id: &str) -> bool {
+    for _ in 0..50 {
+        let finished = PROCESSES
+            .lock()
+            .get(id)
+            .is_none_or(|process| process.finished);
+        if finished {
+            return true;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    false
+}
+
+/// stop the process & run the script again with the same id, the output is cleared
+pub async fn restart_process(id: String) -> Result<ProcessInfo> {
+    let (path, script) = {
+        let processes = PROCESSES.lock();
+        let process = processes
+            .get(&id)
+            .ok_or_else(|| anyhow!("failed to find the process \"id:{id}\""))?;
+        (process.info.path.clone(), process.info.script.clone())
+    };
+    stop_process(id.clone()).await?;
+    if !wait_stopped(&id).await {
+        bail!("the process \"id:{id}\" is still running");
+    }
+    spawn_script(id, path, script).await
+}
+
+/// remove the finished process
+pub fn remove_process(id: String) -> Result<()> {
+    let mut processes = PROCESSES.lock();
+    match processes.get(&id) {
+        Some(process) if process.info.status == ProcessStatus::Running => {
+            bail!("the process \"id:{id}\" is still running")
+        }
+        Some(_) => {
+            processes.remove(&id);
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// get the processes, the processes of the project when the path is set
+pub fn process_list(path: Option<PathBuf>) -> Vec<ProcessInfo> {
+    let mut list = PROCESSES
+        .lock()
+        .values()
+        .filter(|process| path.as_deref().is_none_or(|path| process.info.path == path))
+        .map(|process| process.info.clone())
+        .collect::<Vec<_>>();
+    list.sort_by_key(|info| std::cmp::Reverse(info.started_at));
+    list
+}
+
+/// get the last output lines of the process
+pub fn process_output(id: String) -> Result<Vec<OutputLine>> {
+    PROCESSES
+        .lock()
+        .get(&id)
+        .map(|process| process.output.iter().cloned().collect())
+        .ok_or_else(|| anyhow!("failed to find the process \"id:{id}\""))
+}
+
+/// stop every running process, called when the app exits
+pub async fn stop_all() {
+    let ids = PROCESSES
+        .lock()
+        .values()
+        .filter(|process| process.info.status == ProcessStatus::Running)
+        .map(|process| process.info.id.clone())
+        .collect::<Vec<_>>();
+    for id in ids {
+        log_err!(stop_process(id).await);
+    }
+}
+
+#[test]
+fn test_push_line() {
+    let mut output = VecDeque::new();
+    for index in 0..MAX_LINES + 10 {
+        push_line(
+            &mut output,
+            OutputLine {
+                id: "id".to_string(),
+                stream: "stdout".to_string(),
+                line: index.to_string(),
+            },
+        );
+    }
+    assert_eq!(output.len(), MAX_LINES);
+    assert_eq!(output.front().map(|line| line.line.as_str()), Some("10"));
+}
+
+#[test]
+fn test_package_scripts() {
+    let package = serde_json::from_str::<Package>(
+        r#"{"scripts":{"dev":"vite","build":"vite build","lint":null},"name":"demo"}"#,
+    )
+    .unwrap();
+    let scripts = package.scripts.unwrap().0;
+    assert_eq!(
+        scripts
+            .iter()
+            .map(|script| script.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["dev", "build", "lint"]
+    );
+    assert_eq!(scripts[1].command, "vite build");
+
+    let package = serde_json::from_str::<Package>(r#"{"name":"demo"}"#).unwrap();
+    assert!(package.scripts.is_none());
+}
@@ -0,0 +1,196 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use ignore::WalkBuilder;
+use tauri_plugin_dialog::{DialogExt, FilePath};
+
+use crate::{
+    config::{Config, Project},
+    log_err,
+};
+
+use super::{handle, project, project::PInfo, version_file};
+
+/// the default depth to scan from the root
+const DEFAULT_DEPTH: usize = 4;
+
+/// the files marking a project directory
+const PROJECT_FILES: [&str; 4] = ["package.json", ".nvmdrc", ".nvmrc", ".node-version"];
+
+/// whether the directory contains `package.json` or a version file
+fn is_project_dir(path: &Path) -> bool {
+    PROJECT_FILES.iter().any(|file| path.join(file).is_file())
+}
+
+/// walk the root and collect the project directories
+/// `node_modules`, hidden & git ignored directories are skipped
+/// the sub directories of a project (e.g. workspace packages) are not scanned
+fn find_project_dirs(root: &Path, depth: usize) -> Vec<PathBuf> {
+    let walker = WalkBuilder::new(root)
+        .max_depth(Some(depth))
+        .hidden(true)
+        .git_ignore(true)
+        .require_git(false)
+        .follow_links(false)
+        .filter_entry(|entry| {
+            let is_dir = entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_dir());
+            if !is_dir || entry.file_name() == "node_modules" {
+                return false;
+            }
+            // stop at the project directory
+            match entry.path().parent() {
+                Some(parent) if entry.depth() > 0 => !is_project_dir(parent),
+                _ => true,
+            }
+        })
+        .build();
+
+    walker
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.into_path())
+        .filter(|path| is_project_dir(path))
+        .collect()
+}
+
+/// scan the root directory for projects, a directory is picked when the root is `None`
+/// the projects already added are excluded
+pub async fn scan_projects(
+    app_handle: tauri::AppHandle,
+    root: Option<PathBuf>,
+    depth: Option<usize>,
+) -> Result<Option<Vec<PInfo>>> {
+    let root = match root {
+        Some(root) => root,
+        None => match app_handle.dialog().file().blocking_pick_folder() {
+            Some(FilePath::Path(path)) => path,
+            _ => return Ok(None),
+        },
+    };
+    let depth = depth.unwrap_or(DEFAULT_DEPTH);
+
+    let dirs = tokio::task::spawn_blocking(move || find_project_dirs(&root, depth)).await?;
+    let projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+
+    let mut p_info = vec![];
+    for path in dirs {
+        if projects
+            .iter()
+            .any(|project| Path::new(&project.path) == path)
+        {
+            continue;
+        }
+        let detected = version_file::detect_version(&path).await?;
+        p_info.push(PInfo {
+            path,
+            version: detected.as_ref().and_then(|d| d.version.clone()),
+            source: detected.as_ref().map(|d| d.source.clone()),
+            spec: detected.map(|d| d.spec),
+        });
+    }
+
+    Ok(Some(p_info))
+}
+
+/// add the scanned projects to the front of the project list
+/// the version detected from the other version files is written to the maintained version files
+pub async fn import_projects(list: Vec<PInfo>) -> Result<Vec<Project>> {
+    let mut projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+    let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+
+    let mut added = vec![];
+    for info in &list {
+        let path = info.path.to_string_lossy().to_string();
+        if projects.iter().any(|project| project.path == path)
+            || added.iter().any(|project: &Project| project.path == path)
+        {
+            continue;
+        }
+        let name = info
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.clone());
+        added.push(Project {
+            active: true,
+            name,
+            path,
+            version: info.version.clone(),
+            create_at: Some(now.clone()),
+            update_at: Some(now.clone()),
+            ..Project::default()
+        });
+    }
+
+    if added.is_empty() {
+        return Ok(added);
+    }
+
+    projects.splice(0..0, added.iter().cloned());
+    Config::projects().draft().update_list(&projects)?;
+    Config::projects().apply();
+    Config::projects().data().save_file()?;
+
+    // the saved projects with the assigned ids
+    let added = { Config::projects().latest().get_list() }
+        .unwrap_or_default()
+        .into_iter()
+        .take(added.len())
+        .collect::<Vec<_>>();
+
+    for info in &list {
+        let imported = added
+            .iter()
+            .any(|project| Path::new(&project.path) == info.path);
+        if let (true, Some(version)) = (imported, &info.version) {
+            if info.source.as_deref() != Some(".nvmdrc") {
+                log_err!(project::sync_project_version(info.path.clone(), version).await);
+            }
+        }
+    }
+
+    log_err!(handle::Handle::update_systray_part_with_emit(
+        "call-projects-update",
+        ""
+    ));
+
+    Ok(added)
+}
+
+#[test]
+fn test_find_project_dirs() {
+    let root = std::env::temp_dir().join("nvmd-scanner-test");
+    let _ = std::fs::remove_dir_all(&root);
+    for dir in [
+        "app/packages/ui",
+        "app/node_modules/lodash",
+        "lib",
+        "ignored/demo",
+        ".hidden/demo",
+        "nested/deep/deeper/demo",
+    ] {
+        std::fs::create_dir_all(root.join(dir)).unwrap();
+    }
+    for file in [
+        "app/package.json",
+        "app/packages/ui/package.json",
+        "app/node_modules/lodash/package.json",
+        "lib/.nvmrc",
+        "ignored/demo/package.json",
+        ".hidden/demo/package.json",
+        "nested/deep/deeper/demo/package.json",
+    ] {
+        std::fs::write(root.join(file), "{}").unwrap();
+    }
+    std::fs::write(root.join(".gitignore"), "ignored/\n").unwrap();
+
+    let mut dirs = find_project_dirs(&root, 3);
+    dirs.sort();
+    assert_eq!(dirs, vec![root.join("app"), root.join("lib")]);
+
+    let dirs = find_project_dirs(&root, 4);
+    assert_eq!(dirs.len(), 3);
+
+    let _ = std::fs::remove_dir_all(&root);
+}
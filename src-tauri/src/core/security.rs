@@ -0,0 +1,148 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use version_compare::{compare, Cmp};
+
+use crate::{
+    config::{Config, NVersion},
+    utils::help,
+};
+
+use super::{
+    handle,
+    usage::{self, UsageKind},
+};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityAlert {
+    /// the version in use
+    pub version: String,
+
+    /// the newest security release on the same major line
+    pub security_version: String,
+
+    /// where the version is used
+    /// `installed`, `default`, `project:{name}`, `group:{name}` or `alias:{name}`
+    pub sources: Vec<String>,
+}
+
+/// get the major part of the version
+fn major(version: &str) -> Option<&str> {
+    version.trim_start_matches('v').split('.').next()
+}
+
+/// find the newer security releases on the same major line for the versions in use
+/// usages: (version, source)
+pub fn check_security_alerts(list: &[NVersion], usages: &[(String, String)]) -> Vec<SecurityAlert> {
+    let mut alerts: Vec<SecurityAlert> = vec![];
+
+    for (version, source) in usages {
+        let version = version.trim_start_matches('v');
+        if let Some(alert) = alerts.iter_mut().find(|alert| alert.version == version) {
+            if !alert.sources.contains(source) {
+                alert.sources.push(source.clone());
+            }
+            continue;
+        }
+
+        let Some(version_major) = major(version) else {
+            continue;
+        };
+
+        let security_version = list
+            .iter()
+            .filter(|item| item.security.unwrap_or(false))
+            .map(|item| item.version.trim_start_matches('v'))
+            .filter(|item| major(item) == Some(version_major))
+            .filter(|item| matches!(compare(item, version), Ok(Cmp::Gt)))
+            .fold(None::<&str>, |newest, item| match newest {
+                Some(newest) if !matches!(compare(item, newest), Ok(Cmp::Gt)) => Some(newest),
+                _ => Some(item),
+            });
+
+        if let Some(security_version) = security_version {
+            alerts.push(SecurityAlert {
+                version: version.to_string(),
+                security_version: security_version.to_string(),
+                sources: vec![source.clone()],
+            });
+        }
+    }
+
+    alerts
+}
+
+/// collect the versions used by installed list, default, projects, groups and aliases
+fn collect_usages() -> Vec<(String, String)> {
+    let mut usages = vec![];
+
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+    for name in installed {
+        let version = help::split_install_name(&name).0.to_string();
+        usages.push((version, "installed".to_string()));
+    }
+
+    for item in usage::collect_usages() {
+        let source = match item.kind {
+            UsageKind::Default => "default".to_string(),
+            UsageKind::Project => format!("project:{}", item.name),
+            UsageKind::Group => format!("group:{}", item.name),
+            UsageKind::Alias => format!("alias:{}", item.name),
+        };
+        // the other archs of the same version share the security releases
+        let version = help::split_install_name(&item.version).0.to_string();
+        // the target prefix of an alias matching no installed version
+        if version.split('.').count() != 3 {
+            continue;
+        }
+        usages.push((version, source));
+    }
+
+    usages
+}
+
+/// get the security report of the versions in use
+pub async fn security_report() -> Result<Vec<SecurityAlert>> {
+    let list = { Config::node().latest().get_list() }.unwrap_or_default();
+    let usages = collect_usages();
+
+    Ok(check_security_alerts(&list, &usages))
+}
+
+/// notify the main window when there are security alerts
+pub async fn notify_security_alerts() -> Result<()> {
+    let alerts = security_report().await?;
+    if !alerts.is_empty() {
+        handle::Handle::emit_to_main("on-security-alerts", alerts)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_check_security_alerts() {
+    let release = |version: &str, security: bool| NVersion {
+        version: version.to_string(),
+        security: Some(security),
+        ..NVersion::default()
+    };
+    let list = vec![
+        release("v20.11.1", true),
+        release("v18.20.2", false),
+        release("v18.20.1", true),
+        release("v18.19.1", true),
+        release("v18.19.0", false),
+    ];
+    let usages = vec![
+        ("18.19.0".to_string(), "installed".to_string()),
+        ("18.19.0".to_string(), "project:demo".to_string()),
+        ("20.11.1".to_string(), "default".to_string()),
+    ];
+
+    let alerts = check_security_alerts(&list, &usages);
+
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].version, "18.19.0");
+    assert_eq!(alerts[0].security_version, "18.20.1");
+    assert_eq!(alerts[0].sources, vec!["installed", "project:demo"]);
+}
@@ -1,6 +1,10 @@
 use crate::core::{node, project};
-use crate::utils::resolve;
-use crate::{cmds, config::Config, log_err};
+use crate::utils::{help, resolve};
+use crate::{
+    cmds,
+    config::{Alias, Config},
+    log_err,
+};
 use anyhow::{bail, Ok, Result};
 use tauri::menu::{AboutMetadataBuilder, CheckMenuItem};
 use tauri::tray::{MouseButton, TrayIconEvent};
@@ -16,20 +20,58 @@ use tauri::{
 
 pub struct Tray {}
 
+/// the owner of the global version items
+const GLOBAL_OWNER: &str = "global";
+
+/// build the menu id `{kind}:{owner}:{value}`
+/// the owner (`global` or a project id) never contains `:`, the value is kept verbatim
+fn menu_id(kind: &str, owner: &str, value: &str) -> String {
+    format!("{kind}:{owner}:{value}")
+}
+
+/// parse the menu id into (kind, owner, value)
+fn parse_menu_id(id: &str) -> Option<(&str, &str, &str)> {
+    let (kind, rest) = id.split_once(':')?;
+    let (owner, value) = rest.split_once(':')?;
+    Some((kind, owner, value))
+}
+
 fn gen_check_menu_items(
     app_handle: &AppHandle,
     versions: &[String],
-    name: &str,
+    owner: &str,
     current: &str,
 ) -> Result<Vec<CheckMenuItem<Wry>>> {
     versions
         .iter()
         .map(|version| {
+            let label = match help::split_install_name(version) {
+                (version, Some(arch)) => format!("v{} ({})", version, arch),
+                (version, None) => format!("v{}", version),
+            };
+            Ok(
+                CheckMenuItemBuilder::with_id(menu_id("version", owner, version), label)
+                    .checked(current == version)
+                    .build(app_handle)?,
+            )
+        })
+        .collect()
+}
+
+fn gen_alias_menu_items(
+    app_handle: &AppHandle,
+    aliases: &[Alias],
+    owner: &str,
+    current: &str,
+) -> Result<Vec<CheckMenuItem<Wry>>> {
+    aliases
+        .iter()
+        .map(|alias| {
             Ok(CheckMenuItemBuilder::with_id(
-                format!("{}_version_{}", name, version),
-                format!("v{}", version),
+                menu_id("version", owner, &alias.name),
+                format!("{} (v{})", alias.name, alias.version),
             )
-            .checked(current == version)
+            .checked(current == alias.name)
             .build(app_handle)?)
         })
         .collect()
@@ -52,7 +94,16 @@ impl Tray {
         let node = Config::node();
         let node = node.latest();
         let installed = node.installed.as_deref().unwrap_or(&[]);
-        let global_current = node.current.as_deref().unwrap_or_default();
+        // aliases
+        let aliases = Config::aliases();
+        let aliases = aliases.latest();
+        let alias_list = aliases.list.as_deref().unwrap_or(&[]);
+        let global_alias = aliases.default.as_deref().unwrap_or_default();
+        // the version item is not checked when the global version follows an alias
+        let global_current = match aliases.default {
+            Some(_) => "",
+            None => node.current.as_deref().unwrap_or_default(),
+        };
 
         let icon_path = app_handle.path().resource_dir()?.join("icons/icon.png");
 
@@ -71,12 +122,14 @@ impl Tray {
             .map(|project| {
                 let project_version = project.version.as_deref().unwrap_or("");
                 let version_items =
-                    gen_check_menu_items(app_handle, &installed, &project.name, project_version)?;
+                    gen_check_menu_items(app_handle, &installed, &project.id, project_version)?;
+                let alias_items =
+                    gen_alias_menu_items(app_handle, alias_list, &project.id, project_version)?;
                 let group_items = groups
                     .iter()
                     .map(|group| {
                         Ok(CheckMenuItemBuilder::new(&group.name)
-                            .id(format!("{}_group_{}", &project.name, &group.name))
+                            .id(menu_id("group", &project.id, &group.name))
                             .checked(project_version == &group.name)
                             .build(app_handle)?)
                     })
@@ -85,18 +138,21 @@ impl Tray {
                     .iter()
                     .map(|item| item as &dyn IsMenuItem<Wry>)
                     .collect();
+                let alias_items_refs: Vec<&dyn IsMenuItem<Wry>> = alias_items
+                    .iter()
+                    .map(|item| item as &dyn IsMenuItem<Wry>)
+                    .collect();
                 let group_items_refs: Vec<&dyn IsMenuItem<Wry>> = group_items
                     .iter()
                     .map(|item| item as &dyn IsMenuItem<Wry>)
                     .collect();
 
-                Ok(
-                    SubmenuBuilder::with_id(app_handle, &project.name, &project.name)
-                        .items(&version_items_refs)
-                        .separator()
-                        .items(&group_items_refs)
-                        .build()?,
-                )
+                let mut builder = SubmenuBuilder::with_id(app_handle, &project.id, &project.name)
+                    .items(&version_items_refs);
+                if !alias_items_refs.is_empty() {
+                    builder = builder.separator().items(&alias_items_refs);
+                }
+                Ok(builder.separator().items(&group_items_refs).build()?)
             })
             .collect::<Result<Vec<Submenu<Wry>>>>()?;
         let sub_items_refs: Vec<&dyn IsMenuItem<Wry>> = sub_items
@@ -105,11 +161,22 @@ impl Tray {
             .collect();
 
         let global_menu_items =
-            gen_check_menu_items(app_handle, &installed, "global", global_current)?;
+            gen_check_menu_items(app_handle, &installed, GLOBAL_OWNER, global_current)?;
         let global_menu_items_ref: Vec<&dyn IsMenuItem<Wry>> = global_menu_items
             .iter()
             .map(|item| item as &dyn IsMenuItem<Wry>)
             .collect();
+        let global_alias_items =
+            gen_alias_menu_items(app_handle, alias_list, GLOBAL_OWNER, global_alias)?;
+        let global_alias_items_ref: Vec<&dyn IsMenuItem<Wry>> = global_alias_items
+            .iter()
+            .map(|item| item as &dyn IsMenuItem<Wry>)
+            .collect();
+        let mut global_menu = SubmenuBuilder::with_id(app_handle, "global", "Global (defaule)")
+            .items(&global_menu_items_ref);
+        if !global_alias_items_ref.is_empty() {
+            global_menu = global_menu.separator().items(&global_alias_items_ref);
+        }
 
         Ok(MenuBuilder::with_id(app_handle, "tray_menu")
             .item(
@@ -117,11 +184,7 @@ impl Tray {
                     .build(app_handle)?,
             )
             .separator()
-            .item(
-                &SubmenuBuilder::with_id(app_handle, "global", "Global (defaule)")
-                    .items(&global_menu_items_ref)
-                    .build()?,
-            )
+            .item(&global_menu.build()?)
             .items(&sub_items_refs)
             .separator()
             .items(&[
@@ -206,37 +269,58 @@ impl Tray {
                     window.open_devtools();
                 }
             }
-            id if id.contains("_version_") => Tray::handle_version_change(id),
-            id if id.contains("_group_") => Tray::handle_group_change(id),
-            _ => {}
-        }
-    }
-
-    fn handle_version_change(id: &str) {
-        let info = id.split("_version_").collect::<Vec<_>>();
-        if info.len() == 2 {
-            let name = info[0].to_owned();
-            let version = info[1].to_owned();
-            if name == "global" {
-                spawn(async move {
-                    log_err!(node::update_current_from_menu(version).await);
-                });
-            } else {
-                spawn(async move {
-                    log_err!(project::change_with_version(name, version).await);
-                });
-            }
+            id => match parse_menu_id(id) {
+                Some(("version", owner, version)) => Tray::handle_version_change(owner, version),
+                Some(("group", owner, group_name)) => Tray::handle_group_change(owner, group_name),
+                _ => {}
+            },
         }
     }
 
-    fn handle_group_change(id: &str) {
-        let info = id.split("_group_").collect::<Vec<_>>();
-        if info.len() == 2 {
-            let name = info[0].to_owned();
-            let group_name = info[1].to_owned();
+    fn handle_version_change(owner: &str, version: &str) {
+        let owner = owner.to_owned();
+        let version = version.to_owned();
+        if owner == GLOBAL_OWNER {
             spawn(async move {
-                log_err!(project::change_with_group(name, group_name).await);
+                log_err!(node::update_current_from_menu(version).await);
+            });
+        } else {
+            spawn(async move {
+                log_err!(project::change_with_version(owner, version).await);
             });
         }
     }
+
+    fn handle_group_change(owner: &str, group_name: &str) {
+        let id = owner.to_owned();
+        let group_name = group_name.to_owned();
+        spawn(async move {
+            log_err!(project::change_with_group(id, group_name).await);
+        });
+    }
+}
+
+#[test]
+fn test_menu_id() {
+    let id = menu_id(
+        "version",
+        "1c9e6f0e-7d2b-4e43-9a57-3c1f2b7e8d11",
+        "18.19.0-x64",
+    );
+    assert_eq!(
+        parse_menu_id(&id),
+        Some((
+            "version",
+            "1c9e6f0e-7d2b-4e43-9a57-3c1f2b7e8d11",
+            "18.19.0-x64"
+        ))
+    );
+
+    let id = menu_id("group", GLOBAL_OWNER, "my_version_tool:v2");
+    assert_eq!(
+        parse_menu_id(&id),
+        Some(("group", GLOBAL_OWNER, "my_version_tool:v2"))
+    );
+
+    assert_eq!(parse_menu_id("open_window"), None);
 }
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+use super::alias;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageKind {
+    /// the global default version
+    Default,
+
+    /// a project uses the version directly
+    Project,
+
+    /// a group uses the version
+    Group,
+
+    /// an alias resolves to the version
+    Alias,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Usage {
+    /// the resolved node version
+    pub version: String,
+
+    /// who uses the version
+    pub kind: UsageKind,
+
+    /// the project, group or alias name, `global` for the default
+    pub name: String,
+
+    /// the project id
+    pub id: Option<String>,
+
+    /// the project path
+    pub path: Option<String>,
+}
+
+/// collect the versions used by the default, projects, groups and aliases
+/// projects using a group or an alias are covered by that group or alias
+/// an alias matching no installed version uses its target, e.g. `16`
+pub fn collect_usages() -> Vec<Usage> {
+    let mut usages = vec![];
+
+    let aliases = Config::aliases().latest().clone();
+    for item in aliases.list.as_deref().unwrap_or_default() {
+        usages.push(Usage {
+            version: alias::resolve_version(&item.name).unwrap_or_else(|_| item.version.clone()),
+            kind: UsageKind::Alias,
+            name: item.name.clone(),
+            id: None,
+            path: None,
+        });
+    }
+    let is_alias = |version: &str| alias::find_alias(version).is_some();
+
+    if aliases.default.is_none() {
+        let current = { Config::node().latest().get_current() }.unwrap_or_default();
+        if !current.is_empty() {
+            usages.push(Usage {
+                version: current,
+                kind: UsageKind::Default,
+                name: "global".to_string(),
+                id: None,
+                path: None,
+            });
+        }
+    }
+
+    let groups = { Config::groups().latest().get_list() }.unwrap_or_default();
+    for group in &groups {
+        if let Some(version) = group.version.as_deref().filter(|v| !is_alias(v)) {
+            usages.push(Usage {
+                version: version.to_string(),
+                kind: UsageKind::Group,
+                name: group.name.clone(),
+                id: None,
+                path: None,
+            });
+        }
+    }
+
+    let projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+    for project in &projects {
+        let Some(version) = project.version.as_deref() else {
+            continue;
+        };
+        if is_alias(version) || groups.iter().any(|group| group.name == version) {
+            continue;
+        }
+        usages.push(Usage {
+            version: version.to_string(),
+            kind: UsageKind::Project,
+            name: project.name.clone(),
+            id: Some(project.id.clone()),
+            path: Some(project.path.clone()),
+        });
+    }
+
+    usages
+}
+
+/// get the usages of the version
+pub fn version_usages(version: &str) -> Vec<Usage> {
+    let version = version.trim_start_matches('v');
+    collect_usages()
+        .into_iter()
+        .filter(|usage| usage.version.trim_start_matches('v') == version)
+        .collect()
+}
@@ -0,0 +1,517 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use node_semver::{Range, Version};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{Config, NVersion},
+    utils::help,
+};
+
+/// the plain version files in order of precedence
+const VERSION_FILES: [&str; 3] = [".nvmdrc", ".nvmrc", ".node-version"];
+
+/// the version files which can be kept up to date
+pub const WRITABLE_FILES: [&str; 4] = [".nvmdrc", ".nvmrc", ".node-version", "package.json#volta"];
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedVersion {
+    /// where the version was read from
+    /// `.nvmdrc`, `.nvmrc`, `.node-version`, `package.json#volta` or `package.json#engines`
+    pub source: String,
+
+    /// the version or range as written
+    pub spec: String,
+
+    /// the proposed version, an installed version matching the spec
+    /// or the exact version when it is not installed
+    pub version: Option<String>,
+}
+
+/// read the first meaningful line of the version file
+pub async fn read_spec(path: &Path) -> Option<String> {
+    let content = help::async_read_string(&path.to_path_buf()).await.ok()?;
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .find(|line| !line.is_empty())
+        .map(|line| line.to_string())
+}
+
+/// read the version spec of the project
+/// precedence: `.nvmdrc` > `.nvmrc` > `.node-version` > `package.json#volta` > `package.json#engines`
+async fn read_project_spec(path: &Path) -> Option<(String, String)> {
+    for name in VERSION_FILES {
+        if let Some(spec) = read_spec(&path.join(name)).await {
+            return Some((name.to_string(), spec));
+        }
+    }
+
+    let package = help::async_read_json::<serde_json::Value>(&path.join("package.json"))
+        .await
+        .ok()?;
+    if let Some(spec) = package["volta"]["node"].as_str() {
+        return Some(("package.json#volta".to_string(), spec.trim().to_string()));
+    }
+    if let Some(spec) = package["engines"]["node"].as_str() {
+        return Some(("package.json#engines".to_string(), spec.trim().to_string()));
+    }
+
+    None
+}
+
+/// resolve the spec against the installed versions (sorted from the newest)
+/// supports exact versions, semver ranges, `node`, `latest`, `lts/*` and `lts/{codename}`
+pub fn resolve_spec(spec: &str, installed: &[String], list: &[NVersion]) -> Option<String> {
+    // only the platform default arch
+    let installed = installed
+        .iter()
+        .filter(|name| help::split_install_name(name).1.is_none())
+        .collect::<Vec<_>>();
+    let spec = spec.trim();
+
+    match spec.to_lowercase().as_str() {
+        "node" | "latest" | "current" | "stable" | "*" => {
+            return installed.first().map(|version| version.to_string());
+        }
+        lts if lts.starts_with("lts/") || lts == "lts" => {
+            let codename = lts.trim_start_matches("lts").trim_start_matches('/');
+            let is_lts = |version: &str| {
+                list.iter().any(|item| {
+                    item.version.trim_start_matches('v') == version
+                        && match item.lts.as_ref().and_then(|lts| lts.as_str()) {
+                            Some(name) => {
+                                codename.is_empty()
+                                    || codename == "*"
+                                    || name.eq_ignore_ascii_case(codename)
+                            }
+                            None => false,
+                        }
+                })
+            };
+            return installed
+                .iter()
+                .find(|version| is_lts(version))
+                .map(|version| version.to_string());
+        }
+        _ => {}
+    }
+
+    let spec = spec.trim_start_matches('v');
+    if let Ok(version) = Version::parse(spec) {
+        return Some(version.to_string());
+    }
+
+    let range = Range::parse(spec).ok()?;
+    installed
+        .into_iter()
+        .find(|version| {
+            Version::parse(version)
+                .map(|version| range.satisfies(&version))
+                .unwrap_or(false)
+        })
+        .map(|version| version.to_string())
+}
+
+/// detect the node version of the project from its version files
+pub async fn detect_version(path: &Path) -> Result<Option<DetectedVersion>> {
+    let Some((source, spec)) = read_project_spec(path).await else {
+        return Ok(None);
+    };
+
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+    let list = { Config::node().latest().get_list() }.unwrap_or_default();
+    // the `.nvmdrc` may contain an alias or an installation name
+    let version = if source == VERSION_FILES[0] && installed.contains(&spec) {
+        Some(spec.clone())
+    } else {
+        resolve_spec(&spec, &installed, &list)
+    };
+
+    Ok(Some(DetectedVersion {
+        source,
+        spec,
+        version,
+    }))
+}
+
+/// get the version files maintained for the project
+/// `.nvmdrc` is always maintained since the `nvmd` shim reads it,
+/// the other files from the project setting first, then the global setting
+pub fn maintained_files(path: &Path) -> Vec<String> {
+    let projects = { Config::projects().latest().get_list() }.unwrap_or_default();
+    let extra = projects
+        .into_iter()
+        .find(|project| Path::new(&project.path) == path)
+        .and_then(|project| project.version_files)
+        .or_else(|| Config::settings().latest().get_version_files())
+        .unwrap_or_default();
+
+    with_nvmdrc(extra)
+}
+
+/// put `.nvmdrc` first & drop the unknown or repeated files
+fn with_nvmdrc(extra: Vec<String>) -> Vec<String> {
+    let mut files = vec![VERSION_FILES[0].to_string()];
+    for file in extra {
+        if WRITABLE_FILES.contains(&file.as_str()) && !files.contains(&file) {
+            files.push(file);
+        }
+    }
+    files
+}
+
+/// a member of a JSON object in the text
+struct Member {
+    /// the decoded key
+    key: String,
+
+    /// the position of the key
+    start: usize,
+
+    /// the text between the key and the value, e.g. `": "`
+    separator: String,
+
+    /// the span of the value
+    value: std::ops::Range<usize>,
+}
+
+/// skip the whitespace from the position
+fn skip_whitespace(bytes: &[u8], mut pos: usize) -> usize {
+    while bytes.get(pos).is_some_and(|b| b.is_ascii_whitespace()) {
+        pos += 1;
+    }
+    pos
+}
+
+/// get the end (exclusive) of the JSON value starting at the position
+fn value_end(bytes: &[u8], pos: usize) -> Result<usize> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, &b) in bytes.iter().enumerate().skip(pos) {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if b == b'\\' {
+                escaped = true;
+            } else if b == b'"' {
+                in_string = false;
+                if depth == 0 {
+                    return Ok(index + 1);
+                }
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' if depth == 0 => return Ok(index),
+            b'}' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(index + 1);
+                }
+            }
+            b',' if depth == 0 => return Ok(index),
+            b if depth == 0 && b.is_ascii_whitespace() => return Ok(index),
+            _ => {}
+        }
+    }
+    if depth > 0 || in_string {
+        bail!("unexpected end of the JSON");
+    }
+    Ok(bytes.len())
+}
+
+/// get the members of the JSON object whose `{` is at the position
+fn object_members(content: &str, pos: usize) -> Result<Vec<Member>> {
+    let bytes = content.as_bytes();
+    let mut members = vec![];
+    let mut pos = skip_whitespace(bytes, pos + 1);
+    loop {
+        match bytes.get(pos) {
+            Some(b'}') => return Ok(members),
+            Some(b'"') => {
+                let key_end = value_end(bytes, pos)?;
+                let key = serde_json::from_str::<String>(&content[pos..key_end])?;
+                let value_start = skip_whitespace(bytes, skip_whitespace(bytes, key_end) + 1);
+                let end = value_end(bytes, value_start)?;
+                members.push(Member {
+                    key,
+                    start: pos,
+                    separator: content[key_end..value_start].to_string(),
+                    value: value_start..end,
+                });
+                pos = skip_whitespace(bytes, end);
+                if bytes.get(pos) == Some(&b',') {
+                    pos = skip_whitespace(bytes, pos + 1);
+                }
+            }
+            _ => bail!("invalid JSON object"),
+        }
+    }
+}
+
+/// detect the indentation of the JSON text, 2 spaces by default
+fn detect_indent(content: &str) -> &str {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .find(|indent| !indent.is_empty())
+        .unwrap_or("  ")
+}
+
+/// serialize the value with the indentation
+fn to_pretty(value: &serde_json::Value, indent: &str) -> Result<String> {
+    let mut buf = vec![];
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+    value.serialize(&mut serde_json::Serializer::with_formatter(
+        &mut buf, formatter,
+    ))?;
+    Ok(String::from_utf8(buf)?)
+}
+
+/// serialize the value placed after the whitespace of a member
+/// it spans lines at the member's indentation when the members are on their own lines
+fn nested(value: &serde_json::Value, whitespace: &str, indent: &str) -> Result<String> {
+    let Some(line) = whitespace.rfind('\n') else {
+        return Ok(serde_json::to_string(value)?);
+    };
+    let newline = if whitespace[..line].ends_with('\r') {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    Ok(to_pretty(value, indent)?.replace('\n', &format!("{newline}{}", &whitespace[line + 1..])))
+}
+
+/// set `volta.node` of the `package.json` content
+/// only the changed span is edited, the rest of the text is kept as written
+fn update_volta(content: &str, version: &str) -> Result<String> {
+    let mut package = serde_json::from_str::<serde_json::Value>(content)?;
+    if !package.is_object() {
+        bail!("the package.json is not an object");
+    }
+    if !matches!(
+        package.get("volta"),
+        None | Some(serde_json::Value::Object(_))
+    ) {
+        bail!("the \"volta\" field of the package.json is not an object");
+    }
+
+    let indent = detect_indent(content);
+    let root = skip_whitespace(content.as_bytes(), 0);
+    let members = object_members(content, root)?;
+    let Some(first) = members.first() else {
+        // nothing to keep
+        package["volta"]["node"] = serde_json::Value::String(version.to_string());
+        return Ok(format!("{}\n", to_pretty(&package, indent)?));
+    };
+    let whitespace = &content[root + 1..first.start];
+    let node = serde_json::to_string(version)?;
+    let volta = serde_json::json!({ "node": version });
+
+    let (span, text) = match members.iter().find(|member| member.key == "volta") {
+        Some(member) => {
+            let volta_members = object_members(content, member.value.start)?;
+            match (
+                volta_members.iter().find(|member| member.key == "node"),
+                volta_members.first(),
+            ) {
+                (Some(node_member), _) => (node_member.value.clone(), node),
+                // put `node` first, the same way the others are written
+                (None, Some(first)) => (
+                    first.start..first.start,
+                    format!(
+                        "\"node\"{}{node},{}",
+                        first.separator,
+                        &content[member.value.start + 1..first.start]
+                    ),
+                ),
+                (None, None) => (member.value.clone(), nested(&volta, whitespace, indent)?),
+            }
+        }
+        None => {
+            let end = members
+                .last()
+                .map_or(first.value.end, |last| last.value.end);
+            (
+                end..end,
+                format!(
+                    ",{whitespace}\"volta\"{}{}",
+                    first.separator,
+                    nested(&volta, whitespace, indent)?
+                ),
+            )
+        }
+    };
+
+    let mut content = content.to_string();
+    content.replace_range(span, &text);
+    Ok(content)
+}
+
+/// write the version into the version files of the project
+/// `.nvmdrc` keeps the installation name, the others get the plain version
+/// `package.json#volta` is only updated when the `package.json` exists
+pub async fn write_version(path: &Path, version: &str, files: &[String]) -> Result<()> {
+    let plain = help::split_install_name(version).0;
+    for file in files {
+        match file.as_str() {
+            ".nvmdrc" => help::async_save_string(&path.join(file), version).await?,
+            ".nvmrc" | ".node-version" => {
+                help::async_save_string(&path.join(file), &format!("{plain}\n")).await?
+            }
+            "package.json#volta" => {
+                let package_path = path.join("package.json");
+                if !package_path.exists() {
+                    continue;
+                }
+                let content = help::async_read_string(&package_path).await?;
+                let content = update_volta(&content, plain).with_context(|| {
+                    format!("failed to update the file \"{}\"", package_path.display())
+                })?;
+                help::async_save_string(&package_path, &content).await?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// the content of a version file before it was written
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// the file path
+    path: PathBuf,
+
+    /// the content, `None` when the file did not exist
+    content: Option<String>,
+}
+
+/// get the files written by `write_version`
+fn touched_files(path: &Path, files: &[String]) -> Vec<PathBuf> {
+    files
+        .iter()
+        .filter_map(|file| match file.as_str() {
+            ".nvmdrc" | ".nvmrc" | ".node-version" => Some(path.join(file)),
+            "package.json#volta" => Some(path.join("package.json")),
+            _ => None,
+        })
+        .collect()
+}
+
+/// read the version files of the project before writing them
+pub async fn snapshot(path: &Path, files: &[String]) -> Vec<Snapshot> {
+    let mut snapshots = vec![];
+    for path in touched_files(path, files) {
+        let content = help::async_read_string(&path).await.ok();
+        snapshots.push(Snapshot { path, content });
+    }
+    snapshots
+}
+
+/// restore the version files, the files not existing before are removed
+pub async fn restore(snapshots: &[Snapshot]) -> Result<()> {
+    for Snapshot { path, content } in snapshots {
+        match content {
+            Some(content) => help::async_save_string(path, content).await?,
+            None if path.exists() => tokio::fs::remove_file(path).await?,
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_resolve_spec() {
+    let installed = ["20.11.1", "18.19.0", "18.19.0-x64", "16.20.2"].map(String::from);
+    let release = |version: &str, lts: serde_json::Value| NVersion {
+        version: version.to_string(),
+        lts: Some(lts),
+        ..NVersion::default()
+    };
+    let list = vec![
+        release("v20.11.1", serde_json::json!("Iron")),
+        release("v18.19.0", serde_json::json!("Hydrogen")),
+        release("v16.20.2", serde_json::json!("Gallium")),
+    ];
+
+    assert_eq!(
+        resolve_spec("v18.19.0", &installed, &list),
+        Some("18.19.0".to_string())
+    );
+    assert_eq!(
+        resolve_spec("21.0.0", &installed, &list),
+        Some("21.0.0".to_string())
+    );
+    assert_eq!(
+        resolve_spec(">=16 <20", &installed, &list),
+        Some("18.19.0".to_string())
+    );
+    assert_eq!(
+        resolve_spec("^16.0.0", &installed, &list),
+        Some("16.20.2".to_string())
+    );
+    assert_eq!(
+        resolve_spec("lts/hydrogen", &installed, &list),
+        Some("18.19.0".to_string())
+    );
+    assert_eq!(
+        resolve_spec("lts/*", &installed, &list),
+        Some("20.11.1".to_string())
+    );
+    assert_eq!(resolve_spec("^22", &installed, &list), None);
+}
+
+#[test]
+fn test_with_nvmdrc() {
+    assert_eq!(with_nvmdrc(vec![]), vec![".nvmdrc"]);
+    assert_eq!(
+        with_nvmdrc(
+            [".nvmrc", ".nvmdrc", "unknown", ".nvmrc"]
+                .map(String::from)
+                .to_vec()
+        ),
+        vec![".nvmdrc", ".nvmrc"]
+    );
+}
+
+#[test]
+fn test_update_volta() {
+    let content = "{\n\t\"name\": \"demo\",\n\t\"volta\": {\n\t\t\"node\": \"16.20.2\",\n\t\t\"yarn\": \"1.22.19\"\n\t},\n\t\"scripts\": {}\n}\n";
+    assert_eq!(
+        update_volta(content, "18.19.0").unwrap(),
+        content.replace("16.20.2", "18.19.0")
+    );
+
+    // the indentation & the missing trailing newline are kept
+    let content =
+        "{\n    \"name\": \"demo\",\n    \"scripts\": {\n        \"dev\": \"vite\"\n    }\n}";
+    assert_eq!(
+        update_volta(content, "20.11.1").unwrap(),
+        "{\n    \"name\": \"demo\",\n    \"scripts\": {\n        \"dev\": \"vite\"\n    },\n    \"volta\": {\n        \"node\": \"20.11.1\"\n    }\n}"
+    );
+
+    assert_eq!(
+        update_volta(r#"{"name":"demo","volta":{"yarn":"1.22.19"}}"#, "20.11.1").unwrap(),
+        r#"{"name":"demo","volta":{"node":"20.11.1","yarn":"1.22.19"}}"#
+    );
+    assert_eq!(
+        update_volta(r#"{"volta":{}}"#, "20.11.1").unwrap(),
+        r#"{"volta":{"node":"20.11.1"}}"#
+    );
+    assert_eq!(
+        update_volta("{}", "20.11.1").unwrap(),
+        "{\n  \"volta\": {\n    \"node\": \"20.11.1\"\n  }\n}\n"
+    );
+
+    assert!(update_volta("[]", "20.11.1").is_err());
+    assert!(update_volta(r#"{"volta":"20.11.1"}"#, "20.11.1").is_err());
+    assert!(update_volta(r#"{"volta":[]}"#, "20.11.1").is_err());
+}
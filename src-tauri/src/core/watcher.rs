@@ -0,0 +1,200 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Result;
+use notify_debouncer_mini::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    DebounceEventResult, Debouncer,
+};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tauri::async_runtime::spawn;
+
+use crate::{config::Config, log_err, utils::dirs};
+
+use super::{group, handle, node, project};
+
+static WATCHER: Lazy<Mutex<Option<Debouncer<RecommendedWatcher>>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Debug, Default)]
+struct Changes {
+    /// the install directory changed
+    installed: bool,
+
+    /// the `default` file changed
+    current: bool,
+
+    /// the `projects.json` file changed
+    projects: bool,
+
+    /// the `groups.json` file changed
+    groups: bool,
+}
+
+/// start watching the install directory & nvmd state files
+pub fn init() -> Result<()> {
+    rewatch()
+}
+
+/// (re)start watching
+/// should be called again when the install directory changes
+pub fn rewatch() -> Result<()> {
+    let mut debouncer = new_debouncer(Duration::from_millis(500), on_events)?;
+
+    let home_dir = dirs::nvmd_home_dir()?;
+    if home_dir.exists() {
+        debouncer
+            .watcher()
+            .watch(&home_dir, RecursiveMode::NonRecursive)?;
+    }
+
+    let directory = Config::settings().latest().get_directory();
+    if let Some(directory) = directory.map(PathBuf::from) {
+        if directory.exists() {
+            debouncer
+                .watcher()
+                .watch(&directory, RecursiveMode::NonRecursive)?;
+        }
+    }
+
+    // the previous watcher stops when dropped
+    *WATCHER.lock() = Some(debouncer);
+
+    Ok(())
+}
+
+fn on_events(result: DebounceEventResult) {
+    match result {
+        Ok(events) => {
+            let paths = events.into_iter().map(|event| event.path).collect();
+            spawn(async move {
+                log_err!(handle_changes(paths).await);
+            });
+        }
+        Err(err) => log::error!(target: "app", "{err}"),
+    }
+}
+
+/// whether the changed path in the install directory is a version directory created or removed
+/// the temporary entries are skipped: the hidden directories of imports & repairs,
+/// the archives downloaded & unpacked as `node-v{version}-{os}-{arch}`
+fn is_version_change(path: &Path, installed: &[String]) -> bool {
+    let Some(name) = path.file_name().map(|name| name.to_string_lossy()) else {
+        return false;
+    };
+    if !name.starts_with(|c: char| c.is_ascii_digit()) {
+        return false;
+    }
+    if installed.iter().any(|version| *version == name) {
+        !path.exists()
+    } else {
+        path.is_dir()
+    }
+}
+
+/// classify the changed paths
+fn classify(paths: Vec<PathBuf>) -> Result<Changes> {
+    let default_path = dirs::default_version_path()?;
+    let projects_path = dirs::projects_path()?;
+    let groups_path = dirs::groups_path()?;
+    let directory = Config::settings()
+        .latest()
+        .get_directory()
+        .map(PathBuf::from);
+    let installed = { Config::node().latest().get_installed() }.unwrap_or_default();
+
+    let mut changes = Changes::default();
+    for path in paths {
+        if path == default_path {
+            changes.current = true;
+        } else if path == projects_path {
+            changes.projects = true;
+        } else if path == groups_path {
+            changes.groups = true;
+        } else if let Some(directory) = &directory {
+            if &path == directory
+                || (path.parent() == Some(directory.as_path())
+                    && is_version_change(&path, &installed))
+            {
+                changes.installed = true;
+            }
+        }
+    }
+
+    Ok(changes)
+}
+
+/// reload the changed data, rebuild the system tray & notify the main window
+async fn handle_changes(paths: Vec<PathBuf>) -> Result<()> {
+    let changes = classify(paths)?;
+
+    if changes.installed {
+        let installed = Config::node().latest().get_installed();
+        let latest = node::get_installed_list(Some(true)).await?;
+        if installed != latest {
+            handle::Handle::emit_to_main("call-installed-update", latest)?;
+        }
+    }
+
+    if changes.current {
+        let current = Config::node().latest().get_current();
+        let latest = node::get_current(Some(true))?;
+        if current != latest {
+            handle::Handle::update_systray_part_with_emit(
+                "call-current-update",
+                latest.as_deref().unwrap_or_default(),
+            )?;
+        }
+    }
+
+    let mut projects_changed = false;
+    if changes.projects {
+        let projects = serde_json::to_value(Config::projects().latest().get_list())?;
+        let latest = serde_json::to_value(project::project_list(Some(true)).await?)?;
+        projects_changed = projects != latest;
+    }
+    if changes.groups {
+        let groups = serde_json::to_value(Config::groups().latest().get_list())?;
+        let latest = serde_json::to_value(group::group_list(Some(true)).await?)?;
+        projects_changed = projects_changed || groups != latest;
+    }
+    if projects_changed {
+        handle::Handle::update_systray_part_with_emit("call-projects-update", "")?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_is_version_change() {
+    let directory = std::env::temp_dir().join(format!("nvmd-watcher-{}", std::process::id()));
+    for dir in [
+        "18.19.0",
+        "20.11.1",
+        ".20.11.1.import",
+        "node-v20.11.1-linux-x64",
+    ] {
+        std::fs::create_dir_all(directory.join(dir)).unwrap();
+    }
+    std::fs::write(directory.join("node-v20.11.1-linux-x64.tar.gz"), "").unwrap();
+    let installed = ["18.19.0", "16.20.2"].map(String::from);
+
+    // created & removed
+    assert!(is_version_change(&directory.join("20.11.1"), &installed));
+    assert!(is_version_change(&directory.join("16.20.2"), &installed));
+    // unchanged
+    assert!(!is_version_change(&directory.join("18.19.0"), &installed));
+    // temporary
+    for name in [
+        ".20.11.1.import",
+        "node-v20.11.1-linux-x64",
+        "node-v20.11.1-linux-x64.tar.gz",
+    ] {
+        assert!(!is_version_change(&directory.join(name), &installed));
+    }
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
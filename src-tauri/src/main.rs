@@ -46,25 +46,60 @@ fn main() -> tauri::Result<()> {
             // settings
             cmds::read_settings,
             cmds::update_settings,
+            cmds::benchmark_mirrors,
             // node
             cmds::current,
             cmds::set_current,
             cmds::version_list,
             cmds::installed_list,
+            cmds::installed_versions,
             cmds::install_node,
             cmds::uninstall_node,
+            cmds::version_usages,
+            cmds::prune_versions,
+            cmds::migrate_packages,
+            cmds::global_packages,
+            cmds::uninstall_package,
+            cmds::update_package,
             cmds::install_node_cancel,
+            cmds::missing_versions,
+            cmds::install_missing,
+            cmds::verify_installed,
+            cmds::reinstall_node,
+            cmds::discover_versions,
+            cmds::import_versions,
+            cmds::security_report,
             // projects
             cmds::project_list,
             cmds::select_projects,
+            cmds::scan_projects,
+            cmds::import_projects,
+            cmds::detect_project_version,
+            cmds::project_health,
+            cmds::fix_project_health,
             cmds::update_projects,
             cmds::sync_project_version,
             cmds::batch_update_project_version,
+            cmds::update_project_version_files,
+            cmds::update_project_env,
+            cmds::project_scripts,
+            cmds::run_script,
+            cmds::stop_process,
+            cmds::restart_process,
+            cmds::remove_process,
+            cmds::process_list,
+            cmds::process_output,
 						cmds::open_dir,
+            cmds::open_terminal,
+            cmds::open_editor,
             // groups
             cmds::group_list,
             cmds::update_groups,
             cmds::update_group_version,
+            // aliases
+            cmds::alias_list,
+            cmds::set_alias,
+            cmds::remove_alias,
             // configration
             cmds::configration_export,
             cmds::configration_import,
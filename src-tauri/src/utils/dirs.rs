@@ -1,6 +1,9 @@
 use anyhow::Result;
 use dirs::home_dir;
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 use tauri::Manager;
 
 use crate::core::handle;
@@ -24,6 +27,11 @@ pub fn projects_path() -> Result<PathBuf> {
     Ok(nvmd_home_dir()?.join("projects.json"))
 }
 
+/// get the `aliases.json` file path
+pub fn aliases_path() -> Result<PathBuf> {
+    Ok(nvmd_home_dir()?.join("aliases.json"))
+}
+
 /// get the `groups.json` file path
 pub fn groups_path() -> Result<PathBuf> {
     Ok(nvmd_home_dir()?.join("groups.json"))
@@ -63,6 +71,42 @@ pub fn default_install_dir() -> PathBuf {
     }
 }
 
+/// get the node executable path in the version directory
+pub fn node_exe_path(version_dir: &Path) -> PathBuf {
+    if cfg!(target_os = "windows") {
+        version_dir.join("node.exe")
+    } else {
+        version_dir.join("bin/node")
+    }
+}
+
+/// get the dir containing the node & npm executables in the version directory
+pub fn node_bin_dir(version_dir: &Path) -> PathBuf {
+    if cfg!(target_os = "windows") {
+        version_dir.to_path_buf()
+    } else {
+        version_dir.join("bin")
+    }
+}
+
+/// get the npm executable path in the version directory
+pub fn npm_exe_path(version_dir: &Path) -> PathBuf {
+    if cfg!(target_os = "windows") {
+        version_dir.join("npm.cmd")
+    } else {
+        version_dir.join("bin/npm")
+    }
+}
+
+/// get the global `node_modules` dir in the version directory
+pub fn node_modules_dir(version_dir: &Path) -> PathBuf {
+    if cfg!(target_os = "windows") {
+        version_dir.join("node_modules")
+    } else {
+        version_dir.join("lib/node_modules")
+    }
+}
+
 /// get the resources dir
 pub fn app_resources_dir() -> Result<PathBuf> {
     let handle = handle::Handle::global();
@@ -1,6 +1,15 @@
 use anyhow::{bail, Context, Result};
 use serde::{de::DeserializeOwned, Serialize};
-use std::{fs, path::PathBuf};
+use std::{
+    cmp::Ordering,
+    env,
+    ffi::{OsStr, OsString},
+    fs,
+    path::{Path, PathBuf},
+};
+use version_compare::{compare, Cmp};
+
+use super::dirs;
 
 pub fn read_string(path: &PathBuf) -> Result<String> {
     fs::read_to_string(path)
@@ -105,6 +114,10 @@ pub fn read_installed(path: &String) -> Result<Vec<String>> {
     for entry in fs::read_dir(&directory)? {
         let entry = entry?;
         let version = entry.file_name().to_string_lossy().to_string();
+        // skip the temporary directories
+        if version.starts_with('.') {
+            continue;
+        }
         let node_path = directory.clone();
         #[cfg(target_os = "windows")]
         let node_path = node_path.join(&version).join("node.exe");
@@ -118,6 +131,260 @@ pub fn read_installed(path: &String) -> Result<Vec<String>> {
     Ok(versions)
 }
 
+/// split the installation directory name into version & arch
+/// e.g. `16.20.2-x64` => (`16.20.2`, Some(`x64`))
+pub fn split_install_name(name: &str) -> (&str, Option<&str>) {
+    match name.split_once('-') {
+        Some((version, arch)) => (version, Some(arch)),
+        None => (name, None),
+    }
+}
+
+/// sort the installation directory names from the newest
+/// the platform default arch comes before the other archs of the same version
+pub fn compare_installed(a: &str, b: &str) -> Ordering {
+    let (a_version, a_arch) = split_install_name(a);
+    let (b_version, b_arch) = split_install_name(b);
+    match compare(b_version, a_version) {
+        Ok(Cmp::Lt) => Ordering::Less,
+        Ok(Cmp::Gt) => Ordering::Greater,
+        _ => a_arch.cmp(&b_arch),
+    }
+}
+
+/// prepend the dir to the `PATH` environment variable
+pub fn prepend_path(dir: &Path) -> Result<OsString> {
+    let mut paths = vec![dir.to_path_buf()];
+    if let Some(path) = env::var_os("PATH") {
+        paths.extend(env::split_paths(&path));
+    }
+    Ok(env::join_paths(paths)?)
+}
+
+/// create an npm command of the version directory
+/// the version's node is used first & global packages are installed into the version directory
+pub fn npm_command(version_dir: &Path) -> Result<tokio::process::Command> {
+    let mut command = async_command(dirs::npm_exe_path(version_dir));
+    command
+        .env("PATH", prepend_path(&dirs::node_bin_dir(version_dir))?)
+        .env("npm_config_prefix", version_dir);
+    Ok(command)
+}
+
+/// create a command without popping up the console window on Windows
+pub fn async_command<S: AsRef<OsStr>>(program: S) -> tokio::process::Command {
+    #[allow(unused_mut)]
+    let mut command = tokio::process::Command::new(program);
+    #[cfg(target_os = "windows")]
+    command.creation_flags(0x08000000);
+    command
+}
+
+/// create a symbolic link to the directory
+#[cfg(unix)]
+pub fn symlink_dir(source: &Path, dest: &Path) -> Result<()> {
+    std::os::unix::fs::symlink(source, dest)?;
+    Ok(())
+}
+
+/// create a directory junction to the directory
+/// a symbolic link needs Developer Mode or the administrator rights on Windows, a junction does not
+#[cfg(windows)]
+pub fn symlink_dir(source: &Path, dest: &Path) -> Result<()> {
+    use std::os::windows::process::CommandExt;
+
+    // the junction target must be absolute
+    let source = std::path::absolute(source)?;
+    let output = std::process::Command::new("cmd")
+        .args(["/C", "mklink", "/J"])
+        .arg(dest)
+        .arg(&source)
+        .creation_flags(0x08000000)
+        .output()?;
+    if !output.status.success() {
+        bail!(
+            "failed to link \"{}\" to \"{}\": {}",
+            dest.display(),
+            source.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// whether the path is a symbolic link or a junction, e.g. a version linked from a read-only source
+/// nothing is written into a linked version directory
+pub fn is_symlink(path: &Path) -> bool {
+    fs::symlink_metadata(path)
+        .map(|metadata| metadata.file_type().is_symlink())
+        .unwrap_or(false)
+}
+
+/// the source directories as written & canonicalized, to match the links whose target is gone
+fn source_paths(sources: &[String]) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    for source in sources {
+        paths.push(PathBuf::from(source));
+        if let Ok(path) = fs::canonicalize(source) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// get the symbolic links in the install directory pointing into the source directories
+fn source_links(directory: &Path, sources: &[PathBuf]) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .filter(|entry| is_symlink(&entry.path()))
+        .filter_map(|entry| {
+            let target = fs::read_link(entry.path()).ok()?;
+            let target = match target.is_absolute() {
+                true => target,
+                false => directory.join(target),
+            };
+            sources
+                .iter()
+                .any(|source| target.starts_with(source))
+                .then(|| {
+                    (
+                        entry.file_name().to_string_lossy().to_string(),
+                        entry.path(),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// get the versions linked from the read-only source directories, nothing is written
+pub fn linked_sources(directory: &String, sources: &[String]) -> Vec<String> {
+    let mut readonly = source_links(Path::new(directory), &source_paths(sources))
+        .into_iter()
+        .filter(|(_, link)| link.exists())
+        .map(|(version, _)| version)
+        .collect::<Vec<_>>();
+    readonly.sort();
+    readonly
+}
+
+/// link the versions of the read-only source directories into the install directory,
+/// so that the `nvmd` shim can resolve them
+/// the versions in the install directory take precedence, the links whose target is gone are removed
+/// return the read-only versions
+pub fn link_sources(directory: &String, sources: &[String]) -> Vec<String> {
+    let directory_path = PathBuf::from(directory);
+    if !directory_path.exists() {
+        return vec![];
+    }
+
+    let paths = source_paths(sources);
+    for (version, link) in source_links(&directory_path, &paths) {
+        if !link.exists() {
+            if let Err(err) = remove_link(&link) {
+                log::error!(target: "app", "failed to unlink the version \"{version}\": {err}");
+            }
+        }
+    }
+
+    for source in sources
+        .iter()
+        .filter_map(|source| fs::canonicalize(source).ok())
+    {
+        let versions =
+            read_installed(&source.to_string_lossy().to_string()).unwrap_or_else(|err| {
+                log::error!(target: "app", "{err}");
+                vec![]
+            });
+        for version in versions {
+            let dest = directory_path.join(&version);
+            if fs::symlink_metadata(&dest).is_err() {
+                if let Err(err) = symlink_dir(&source.join(&version), &dest) {
+                    log::error!(target: "app", "failed to link the version \"{version}\": {err}");
+                }
+            }
+        }
+    }
+
+    linked_sources(directory, sources)
+}
+
+/// get the versions registered from other version managers (nvm, fnm, volta...)
+/// the symbolic links in the install directory not from the read-only sources
+pub fn external_versions(directory: &String, readonly: &[String]) -> Vec<String> {
+    let mut external = read_installed(directory)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|version| !readonly.contains(version))
+        .filter(|version| is_symlink(&Path::new(directory).join(version)))
+        .collect::<Vec<_>>();
+    external.sort();
+    external
+}
+
+/// remove the links of the source directories no longer used
+pub fn unlink_sources(directory: &String, sources: &[String]) -> Result<()> {
+    for (_, link) in source_links(Path::new(directory), &source_paths(sources)) {
+        remove_link(&link)?;
+    }
+    Ok(())
+}
+
+/// remove the symbolic link, not the directory it points to
+fn remove_link(link: &Path) -> Result<()> {
+    #[cfg(unix)]
+    fs::remove_file(link)?;
+    // a junction is removed as a directory on Windows
+    #[cfg(windows)]
+    fs::remove_dir(link)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_link_sources() {
+    let root = env::temp_dir().join(format!("nvmd-link-sources-{}", std::process::id()));
+    let directory = root.join("versions");
+    let source = root.join("source");
+    fs::create_dir_all(source.join("18.19.0/bin")).unwrap();
+    fs::write(source.join("18.19.0/bin/node"), "").unwrap();
+    fs::create_dir_all(directory.join("20.11.1/bin")).unwrap();
+    fs::write(directory.join("20.11.1/bin/node"), "").unwrap();
+
+    let directory_str = directory.to_string_lossy().to_string();
+    let sources = vec![source.to_string_lossy().to_string()];
+    assert!(linked_sources(&directory_str, &sources).is_empty());
+    assert_eq!(link_sources(&directory_str, &sources), vec!["18.19.0"]);
+    assert!(is_symlink(&directory.join("18.19.0")));
+    assert!(!is_symlink(&directory.join("20.11.1")));
+
+    // the version removed from the source
+    fs::remove_dir_all(source.join("18.19.0")).unwrap();
+    assert!(link_sources(&directory_str, &sources).is_empty());
+    assert!(fs::symlink_metadata(directory.join("18.19.0")).is_err());
+
+    // the source removed from the settings
+    fs::create_dir_all(source.join("16.20.2/bin")).unwrap();
+    fs::write(source.join("16.20.2/bin/node"), "").unwrap();
+    assert_eq!(link_sources(&directory_str, &sources), vec!["16.20.2"]);
+    unlink_sources(&directory_str, &sources).unwrap();
+    assert!(fs::symlink_metadata(directory.join("16.20.2")).is_err());
+    assert!(source.join("16.20.2/bin/node").exists());
+    assert!(directory.join("20.11.1/bin/node").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_compare_installed() {
+    let mut installed = ["16.20.2-x64", "18.19.0", "16.20.2", "9.11.2"].map(String::from);
+    installed.sort_by(|a, b| compare_installed(a, b));
+    assert_eq!(installed, ["18.19.0", "16.20.2", "16.20.2-x64", "9.11.2"]);
+}
+
 #[macro_export]
 macro_rules! log_err {
     ($result: expr) => {
@@ -3,7 +3,7 @@ use tauri::{App, AppHandle, Manager};
 
 use crate::{
     config::Config,
-    core::{handle, tray},
+    core::{handle, tray, watcher},
     log_err, trace_err,
     utils::migrate,
 };
@@ -17,6 +17,7 @@ pub fn resolve_setup(app: &mut App) -> Result<()> {
 
     log_err!(migrate::init());
     log_err!(tray::Tray::update_systray(&app.app_handle()));
+    log_err!(watcher::init());
 
     let silent_start = { Config::settings().data().enable_silent_start };
     if !silent_start.unwrap_or(false) {
@@ -10,6 +10,7 @@ import {
 } from 'react';
 
 import { useTranslation } from 'react-i18next';
+import { toast } from 'sonner';
 import { applyTheme } from '@/lib/utils';
 import { getCurrent } from '@/services/api';
 import { updateSettings } from '@/services/cmds';
@@ -78,7 +79,7 @@ export function AppProvider({
 	);
 
 	const sysTheme = useRef<SystemTheme>(defaultSysTheme);
-	const { i18n } = useTranslation();
+	const { t, i18n } = useTranslation();
 
 	// theme
 	useEffect(() => {
@@ -105,6 +106,61 @@ export function AppProvider({
 		i18n.changeLanguage(settings.locale);
 	}, [settings.locale]);
 
+	// the security releases of the versions in use
+	useEffect(() => {
+		const unlisted = getCurrent().listen<Nvmd.SecurityAlert[]>(
+			'on-security-alerts',
+			({ payload }) => {
+				payload.forEach(({ version, securityVersion, sources }) => {
+					toast.warning(
+						t('Security-Alert', { version, securityVersion }),
+						{
+							description: t('Security-Alert-Sources', {
+								sources: sources.join(', '),
+							}),
+							duration: 10000,
+						}
+					);
+				});
+			}
+		);
+
+		return () => {
+			unlisted.then((fn) => fn());
+		};
+	}, []);
+
+	// the global packages installed in the background after installing a version
+	useEffect(() => {
+		const onReport = ({ payload }: { payload: Nvmd.PackageResult[] }) => {
+			const failed = payload.filter(({ error }) => !!error);
+			const installed = payload.filter(
+				({ error, skipped }) => !error && !skipped
+			);
+			failed.length
+				? toast.error(t('Packages-Failed', { count: failed.length }), {
+						description: failed
+							.map(({ name, error }) => `${name}: ${error}`)
+							.join('\n'),
+				  })
+				: toast.success(t('Packages-Installed', { count: installed.length }));
+		};
+		const unlisted = [
+			getCurrent().listen<Nvmd.PackageResult[]>(
+				'on-packages-installed',
+				onReport
+			),
+			getCurrent().listen<Nvmd.PackageResult[]>(
+				'on-packages-migrated',
+				onReport
+			),
+		];
+
+		return () => {
+			unlisted.forEach((unlisten) => unlisten.then((fn) => fn()));
+		};
+	}, []);
+
 	// color
 	useEffect(() => {
 		document.body.classList.forEach((className) => {
@@ -133,8 +189,13 @@ export function AppProvider({
 			settings.theme,
 			settings.closer,
 			settings.directory,
+			settings.sources,
 			settings.mirror,
 			settings.proxy,
+			settings.version_files,
+			settings.default_packages,
+			settings.terminal,
+			settings.editor,
 		]
 	);
 
@@ -18,6 +18,15 @@ export function compareVersion(version1: string, version2: string): number {
 	return semver.gt(version2, version1) ? -1 : 1;
 }
 
+// split the installation name into the version & the arch
+// a non-default arch is installed as `{version}-{arch}`
+export function splitInstallName(name: string): [string, string | undefined] {
+	const index = name.indexOf('-');
+	return index === -1
+		? [name, undefined]
+		: [name.slice(0, index), name.slice(index + 1)];
+}
+
 export function compareArray(a: string[], b: string[]) {
 	const setA = new Set(a);
 	const setB = new Set(b);
@@ -0,0 +1,13 @@
+const UNITS = ['B', 'KB', 'MB', 'GB', 'TB'];
+
+// format the bytes into a readable size, e.g. `1.5 GB`
+export function formatSize(bytes: number): string {
+	let size = bytes,
+		unit = 0;
+	while (size >= 1024 && unit < UNITS.length - 1) {
+		size /= 1024;
+		unit++;
+	}
+
+	return `${unit ? size.toFixed(1) : size} ${UNITS[unit]}`;
+}
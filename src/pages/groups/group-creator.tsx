@@ -209,8 +209,8 @@ export const GroupCreator: React.FC<Props> = ({
                           placeholder={t('Input-To-Search')}
                         />
                         <MultiSelectList>
-                          {projectsProp.map(({ name, path }) => (
-                            <MultiSelectItem key={path} value={path}>
+                          {projectsProp.map(({ id, name }) => (
+                            <MultiSelectItem key={id} value={id!}>
                               {name}
                             </MultiSelectItem>
                           ))}
@@ -23,7 +23,9 @@ import {
 	MultiSelectValue,
 	Select,
 	SelectContent,
+	SelectGroup,
 	SelectItem,
+	SelectLabel,
 	SelectTrigger,
 	SelectValue,
 } from '@/components/ui';
@@ -42,9 +44,9 @@ import { useAppContext } from '@/app-context';
 import {
 	projectList,
 	installedList,
+	aliasList,
 	groupList,
 	updateGroups,
-	updateProjects,
 	batchUpdateProjectVersion,
 	updateGroupVersion,
 } from '@/services/cmds';
@@ -57,23 +59,27 @@ export async function loader() {
 		projectList(),
 		groupList(),
 		installedList(),
+		aliasList(),
 	]);
 
 	return loadData;
 }
 
 export const Component: React.FC = () => {
-	const [allProjects, allGroups, allInstalledVersions] = useLoaderData() as [
-		Nvmd.Project[],
-		Nvmd.Group[],
-		Array<string>
-	];
+	const [allProjects, allGroups, allInstalledVersions, allAliases] =
+		useLoaderData() as [
+			Nvmd.Project[],
+			Nvmd.Group[],
+			Array<string>,
+			Nvmd.Aliases
+		];
 
 	const [groups, setGroups] = useState<Nvmd.Group[]>(() => allGroups);
 	const [installedVersions, setInstalledVersions] = useState<string[]>(
 		() => allInstalledVersions
 	);
 	const [projects, setProjects] = useState<Nvmd.Project[]>(() => allProjects);
+	const [aliases, setAliases] = useState<Nvmd.Aliases>(() => allAliases);
 	const [loading, setLoading] = useState<boolean>(false);
 
 	const { t } = useTranslation();
@@ -144,19 +150,28 @@ export const Component: React.FC = () => {
 				},
 				maxSize: 170,
 				cell: ({ row }) => {
-					const { projects, name, version } = row.original;
+					const { name, version } = row.original;
 					return (
 						<Select
-							defaultValue={version}
+							value={version}
 							onValueChange={async (newVersion) => {
 								try {
+									const { applied, results } = await updateGroupVersion(
+										name,
+										newVersion
+									);
+									if (!applied) {
+										toast.error(t('Group-Version-Failed'), {
+											description: results
+												.filter(({ error }) => !!error)
+												.map(({ path, error }) => `${path}: ${error}`)
+												.join('\n'),
+										});
+										return;
+									}
+
 									const newGroups = [...groups];
 									newGroups[row.index].version = newVersion;
-									await Promise.all([
-										updateGroupVersion(name, newVersion),
-										batchUpdateProjectVersion(projects, newVersion),
-									]);
-
 									setGroups(newGroups);
 									toast.success(
 										t('Restart-Terminal', { version: `v${newVersion}` })
@@ -170,11 +185,32 @@ export const Component: React.FC = () => {
 								<SelectValue />
 							</SelectTrigger>
 							<SelectContent>
-								{installedVersions.map((version) => (
-									<SelectItem key={version} value={version}>
-										v{version}
-									</SelectItem>
-								))}
+								<SelectGroup>
+									<SelectLabel className='text-muted-foreground'>
+										{t('Versions')}
+									</SelectLabel>
+									{installedVersions.map((version) => (
+										<SelectItem key={version} value={version}>
+											v{version}
+										</SelectItem>
+									))}
+								</SelectGroup>
+								{aliases.list?.length ? (
+									<SelectGroup>
+										<SelectLabel className='text-muted-foreground'>
+											{t('Aliases')}
+										</SelectLabel>
+										{aliases.list.map(({ name, version }) => (
+											<SelectItem
+												key={name}
+												value={name}
+												title={`${name} (v${version})`}
+											>
+												{name}
+											</SelectItem>
+										))}
+									</SelectGroup>
+								) : null}
 							</SelectContent>
 						</Select>
 					);
@@ -192,10 +228,10 @@ export const Component: React.FC = () => {
 					return (
 						<MultiSelect
 							defaultValue={defaultProjects}
-							onValueChange={async (projectsPaths) => {
+							onValueChange={async (projectIds) => {
 								const { added, removed } = compareArray(
 									defaultProjects,
-									projectsPaths
+									projectIds
 								);
 								const [newProjects, newGroups] = await Promise.all([
 									(async () => {
@@ -205,27 +241,22 @@ export const Component: React.FC = () => {
 
 										newProjects.forEach((project) => {
 											// Need addition
-											if (added.length && added.includes(project.path)) {
+											if (added.length && added.includes(project.id!)) {
 												project.version = name;
 												addedPaths.push(project.path);
 											}
 
 											// Need remove
-											if (removed.length && removed.includes(project.path)) {
+											if (removed.length && removed.includes(project.id!)) {
 												project.version = '';
 												removedPaths.push(project.path);
 											}
 										});
 
-										await Promise.all([
-											addedPaths.length
-												? batchUpdateProjectVersion(addedPaths, version)
-												: Promise.resolve(undefined),
-											removedPaths.length
-												? batchUpdateProjectVersion(removedPaths, '')
-												: Promise.resolve(undefined),
-											updateProjects(newProjects),
-										]);
+										addedPaths.length &&
+											(await batchUpdate(addedPaths, version, name));
+										removedPaths.length &&
+											(await batchUpdate(removedPaths, '', ''));
 
 										return newProjects;
 									})(),
@@ -233,18 +264,18 @@ export const Component: React.FC = () => {
 										const newGroups = [...groups];
 										newGroups.forEach((group) => {
 											// If the project is already in other groups, you need to remove it from the original group.
-											const repeatProjects = group.projects.filter((path) =>
-												projectsPaths.includes(path)
+											const repeatProjects = group.projects.filter((id) =>
+												projectIds.includes(id)
 											);
 											if (added.length && repeatProjects.length) {
 												const groupProjects = [...group.projects];
 												group.projects = groupProjects.filter(
-													(path) => !repeatProjects.includes(path)
+													(id) => !repeatProjects.includes(id)
 												);
 											}
 
 											if (group.name === name) {
-												group.projects = projectsPaths;
+												group.projects = projectIds;
 											}
 										});
 										await updateGroups(newGroups);
@@ -264,8 +295,8 @@ export const Component: React.FC = () => {
 									placeholder={t('Input-To-Search')}
 								/>
 								<MultiSelectList>
-									{projects.map(({ name, path }) => (
-										<MultiSelectItem key={path} value={path}>
+									{projects.map(({ id, name }) => (
+										<MultiSelectItem key={id} value={id!}>
 											{name}
 										</MultiSelectItem>
 									))}
@@ -302,17 +333,16 @@ export const Component: React.FC = () => {
 										onClick={async () => {
 											const [newProjects, newGroups] = await Promise.all([
 												(async () => {
-													const newProjects = [...projects];
+													const newProjects = [...projects],
+														paths: string[] = [];
 													newProjects.forEach((project) => {
-														if (groupProjects.includes(project.path)) {
+														if (groupProjects.includes(project.id!)) {
 															project.version = '';
+															paths.push(project.path);
 														}
 													});
 
-													await Promise.all([
-														batchUpdateProjectVersion(groupProjects, ''),
-														updateProjects(newProjects),
-													]);
+													paths.length && (await batchUpdate(paths, '', ''));
 
 													return newProjects;
 												})(),
@@ -337,7 +367,7 @@ export const Component: React.FC = () => {
 				},
 			},
 		],
-		[t, projects, installedVersions.length, groups.length]
+		[t, projects, installedVersions.length, groups.length, aliases]
 	);
 
 	const reorderRow = (draggedRowIndex: number, targetRowIndex: number) => {
@@ -358,21 +388,41 @@ export const Component: React.FC = () => {
 	const onPageReload = async () => {
 		setLoading(true);
 		try {
-			const [allProjects, allGroups, installedVersions] = await Promise.all([
-				projectList(true),
-				groupList(true),
-				installedList(),
-			]);
+			const [allProjects, allGroups, installedVersions, allAliases] =
+				await Promise.all([
+					projectList(true),
+					groupList(true),
+					installedList(),
+					aliasList(true),
+				]);
 
 			setProjects(allProjects);
 			setGroups(allGroups);
 			setInstalledVersions(installedVersions);
+			setAliases(allAliases);
 			toast.success(t('Refresh-successful'));
 		} finally {
 			setLoading(false);
 		}
 	};
 
+	// update the version files & the recorded versions of the projects together
+	const batchUpdate = async (paths: string[], version: string, record: string) => {
+		const { applied, results } = await batchUpdateProjectVersion(
+			paths,
+			version,
+			record
+		);
+		if (!applied) {
+			const description = results
+				.filter(({ error }) => !!error)
+				.map(({ path, error }) => `${path}: ${error}`)
+				.join('\n');
+			toast.error(t('Projects-Version-Failed'), { description });
+			throw new Error(description);
+		}
+	};
+
 	const onSubmit = async (group: Nvmd.Group) => {
 		const { projects: groupProjects, version, name } = group;
 		const [newProjects, newGroups] = await Promise.all([
@@ -384,7 +434,7 @@ export const Component: React.FC = () => {
 					const newProjects = [...projects],
 						paths: string[] = [];
 					newProjects.forEach((project) => {
-						if (groupProjects.includes(project.path)) {
+						if (groupProjects.includes(project.id!)) {
 							needUpdate = true;
 							project.version = name;
 							paths.push(project.path);
@@ -392,11 +442,7 @@ export const Component: React.FC = () => {
 					});
 
 					if (needUpdate) {
-						// todo update
-						await Promise.all([
-							updateProjects(newProjects),
-							batchUpdateProjectVersion(paths, version),
-						]);
+						await batchUpdate(paths, version, name);
 
 						return newProjects;
 					}
@@ -25,8 +25,17 @@ import {
   Input,
   IpInput,
   Switch,
+  Checkbox,
+  Label,
 } from '@/components/ui';
-import { GearIcon, InfoCircledIcon, Pencil2Icon } from '@radix-ui/react-icons';
+import {
+  Cross2Icon,
+  GearIcon,
+  InfoCircledIcon,
+  LightningBoltIcon,
+  Pencil2Icon,
+  PlusIcon,
+} from '@radix-ui/react-icons';
 import {
   Form,
   FormControl,
@@ -38,6 +47,7 @@ import {
   Select,
 } from '@/components/ui';
 import { open as openDialog } from '@tauri-apps/plugin-dialog';
+import { toast } from 'sonner';
 
 import { z } from 'zod';
 import { useForm } from 'react-hook-form';
@@ -45,7 +55,9 @@ import { useTranslation } from 'react-i18next';
 import { zodResolver } from '@hookform/resolvers/zod';
 import { useAppContext } from '@/app-context';
 import { compareObject } from '@/lib/utils';
+import { benchmarkMirrors } from '@/services/cmds';
 import { Closer, Themes } from '@/types';
+import { EXTRA_VERSION_FILES } from '@/pages/projects/version-files';
 
 type Options = NonNullable<AutoCompleteProps['options']>;
 
@@ -56,7 +68,13 @@ const formSchema = z.object({
   theme: z.nativeEnum(Themes),
   closer: z.nativeEnum(Closer),
   directory: z.string().min(1),
+  sources: z.array(z.string()),
   mirror: z.string().url({ message: 'Invalid mirror url' }),
+  version_files: z.array(z.string()),
+  // the package specs separated by spaces
+  default_packages: z.string(),
+  terminal: z.string(),
+  editor: z.string(),
   proxy: z
     .object({
       enabled: z.boolean().default(false),
@@ -92,6 +110,7 @@ const formSchema = z.object({
 const Setting: React.FC<Props> = memo(() => {
   const [open, setOpen] = useState<boolean>(false);
   const [loading, setLoading] = useState<boolean>(false);
+  const [benchmarking, setBenchmarking] = useState<boolean>(false);
 
   const [options, setOptions] = useState<Options>(() => {
     const optStr = localStorage.getItem('nvmd-mirror');
@@ -102,6 +121,11 @@ const Setting: React.FC<Props> = memo(() => {
   const defaultSettings = {
     ...settings,
     proxy: settings.proxy || { enabled: false, ip: '', port: '' },
+    sources: settings.sources || [],
+    version_files: settings.version_files || [],
+    default_packages: (settings.default_packages || []).join(' '),
+    terminal: settings.terminal || '',
+    editor: settings.editor || '',
   };
 
   const form = useForm<z.infer<typeof formSchema>>({
@@ -111,6 +135,34 @@ const Setting: React.FC<Props> = memo(() => {
 
   const { t } = useTranslation();
 
+  // rank the built-in & the cached mirrors, select the fastest one
+  const onBenchmark = async (onChange: (mirror: string) => void) => {
+    setBenchmarking(true);
+    try {
+      const results = await benchmarkMirrors(options);
+      const fastest = results.find(({ error }) => !error);
+      const description = results
+        .map(({ mirror, latency, error }) =>
+          error ? `${mirror}: ${error}` : `${mirror}: ${latency}ms`
+        )
+        .join('\n');
+      if (!fastest) {
+        toast.error(t('Mirror-Benchmark-Failed'), { description });
+        return;
+      }
+
+      onChange(fastest.mirror);
+      toast.success(
+        t('Mirror-Benchmark-Fastest', { mirror: fastest.mirror }),
+        { description }
+      );
+    } catch (err) {
+      toast.error(err as string);
+    } finally {
+      setBenchmarking(false);
+    }
+  };
+
   const onSubmit = async (values: z.infer<typeof formSchema>) => {
     setLoading(true);
     const {
@@ -118,16 +170,27 @@ const Setting: React.FC<Props> = memo(() => {
       theme: newTheme,
       closer: newCloser,
       directory: newDirectory,
+      sources: newSources,
       mirror: newMirror,
       proxy: newProxy,
+      version_files: newVersionFiles,
+      default_packages: defaultPackages,
+      terminal: newTerminal,
+      editor: newEditor,
     } = values;
+    const newDefaultPackages = defaultPackages.split(/\s+/).filter(Boolean);
     if (
       settings.locale === newLocale &&
       settings.theme === newTheme &&
       settings.closer === newCloser &&
       settings.directory === newDirectory &&
+      compareObject(settings.sources || [], newSources) &&
       settings.mirror === newMirror &&
-      compareObject(settings.proxy, newProxy)
+      compareObject(settings.proxy, newProxy) &&
+      compareObject(settings.version_files || [], newVersionFiles) &&
+      compareObject(settings.default_packages || [], newDefaultPackages) &&
+      (settings.terminal || '') === newTerminal &&
+      (settings.editor || '') === newEditor
     ) {
       setLoading(false);
       setOpen(false);
@@ -154,8 +217,13 @@ const Setting: React.FC<Props> = memo(() => {
         theme: newTheme,
         closer: newCloser,
         directory: newDirectory,
+        sources: newSources,
         mirror: newMirror,
         proxy: newProxy,
+        version_files: newVersionFiles as Nvmd.VersionFile[],
+        default_packages: newDefaultPackages,
+        terminal: newTerminal,
+        editor: newEditor,
       });
     } finally {
       setLoading(false);
@@ -186,7 +254,7 @@ const Setting: React.FC<Props> = memo(() => {
           <SheetTitle>{t('Setting')}</SheetTitle>
           <SheetDescription></SheetDescription>
         </SheetHeader>
-        <div className='flex-1 space-y-6'>
+        <div className='flex-1 space-y-6 overflow-y-auto'>
           <Form {...form}>
             <FormField
               control={form.control}
@@ -355,6 +423,70 @@ const Setting: React.FC<Props> = memo(() => {
                 </FormItem>
               )}
             />
+            <FormField
+              control={form.control}
+              name='sources'
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel className='flex items-center gap-1 text-muted-foreground'>
+                    {t('Sources')}
+                    <Tooltip>
+                      <TooltipTrigger asChild>
+                        <InfoCircledIcon className='text-primary cursor-pointer' />
+                      </TooltipTrigger>
+                      <TooltipContent className='w-96 text-accent-foreground bg-accent'>
+                        {t('Sources-Tip')}
+                      </TooltipContent>
+                    </Tooltip>
+                  </FormLabel>
+                  <FormControl>
+                    <div className='space-y-1'>
+                      {field.value.map((source) => (
+                        <div
+                          key={source}
+                          className='flex items-center justify-between'
+                        >
+                          <LabelCopyable className='max-w-64 leading-5 truncate'>
+                            {source}
+                          </LabelCopyable>
+                          <Button
+                            variant='ghost'
+                            size='sm'
+                            icon={<Cross2Icon />}
+                            onClick={() =>
+                              field.onChange(
+                                field.value.filter((item) => item !== source)
+                              )
+                            }
+                          />
+                        </div>
+                      ))}
+                      <Button
+                        variant='secondary'
+                        size='sm'
+                        icon={<PlusIcon />}
+                        onClick={async () => {
+                          const path = await openDialog({
+                            title: t('Directory-Select'),
+                            directory: true,
+                          });
+
+                          if (
+                            typeof path === 'string' &&
+                            !field.value.includes(path)
+                          ) {
+                            field.onChange([...field.value, path]);
+                          }
+                        }}
+                      >
+                        {t('Sources-Add')}
+                      </Button>
+                    </div>
+                  </FormControl>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
             <FormField
               control={form.control}
               name='proxy'
@@ -429,19 +561,124 @@ const Setting: React.FC<Props> = memo(() => {
                     {t('Mirror-Url')}
                   </FormLabel>
                   <FormControl>
-                    <AutoComplete
-                      value={field.value}
-                      shouldFilter={false}
-                      placeholder='mirror url'
-                      options={options}
-                      onChange={field.onChange}
-                    />
+                    <div className='flex items-center gap-2'>
+                      <AutoComplete
+                        value={field.value}
+                        shouldFilter={false}
+                        placeholder='mirror url'
+                        options={options}
+                        onChange={field.onChange}
+                      />
+                      <Button
+                        variant='secondary'
+                        size='sm'
+                        title={t('Mirror-Benchmark')}
+                        loading={benchmarking}
+                        icon={<LightningBoltIcon />}
+                        onClick={() => onBenchmark(field.onChange)}
+                      />
+                    </div>
                   </FormControl>
                   <FormDescription>{t('Mirror-Tip')}</FormDescription>
                   <FormMessage />
                 </FormItem>
               )}
             />
+            <FormField
+              control={form.control}
+              name='version_files'
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel className='text-muted-foreground'>
+                    {t('Version-Files')}
+                  </FormLabel>
+                  <FormControl>
+                    <div className='flex flex-wrap items-center gap-4'>
+                      <div className='flex items-center gap-2'>
+                        <Checkbox checked disabled />
+                        <Label className='font-normal'>.nvmdrc</Label>
+                      </div>
+                      {EXTRA_VERSION_FILES.map((file) => (
+                        <div key={file} className='flex items-center gap-2'>
+                          <Checkbox
+                            checked={field.value.includes(file)}
+                            onCheckedChange={(checked) =>
+                              field.onChange(
+                                checked
+                                  ? [...field.value, file]
+                                  : field.value.filter((item) => item !== file)
+                              )
+                            }
+                          />
+                          <Label className='font-normal'>{file}</Label>
+                        </div>
+                      ))}
+                    </div>
+                  </FormControl>
+                  <FormDescription>{t('Version-Files-Tip')}</FormDescription>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
+            <FormField
+              control={form.control}
+              name='default_packages'
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel className='text-muted-foreground'>
+                    {t('Default-Packages')}
+                  </FormLabel>
+                  <FormControl>
+                    <Input
+                      className='h-8'
+                      placeholder='typescript pnpm@^8'
+                      {...field}
+                    />
+                  </FormControl>
+                  <FormDescription>{t('Default-Packages-Tip')}</FormDescription>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
+            <FormField
+              control={form.control}
+              name='terminal'
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel className='text-muted-foreground'>
+                    {t('Terminal-Command')}
+                  </FormLabel>
+                  <FormControl>
+                    <Input
+                      className='h-8'
+                      placeholder={t('Default')}
+                      {...field}
+                    />
+                  </FormControl>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
+            <FormField
+              control={form.control}
+              name='editor'
+              render={({ field }) => (
+                <FormItem>
+                  <FormLabel className='text-muted-foreground'>
+                    {t('Editor-Command')}
+                  </FormLabel>
+                  <FormControl>
+                    <Input
+                      className='h-8'
+                      placeholder={t('Default')}
+                      {...field}
+                    />
+                  </FormControl>
+                  <FormDescription>{t('Launch-Command-Tip')}</FormDescription>
+                  <FormMessage />
+                </FormItem>
+              )}
+            />
           </Form>
         </div>
         <SheetFooter>
@@ -0,0 +1,130 @@
+import { useEffect, useState } from 'react';
+import {
+  Button,
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Tag,
+} from '@/components/ui';
+import { PlusIcon, TrashIcon } from '@radix-ui/react-icons';
+
+import { toast } from 'sonner';
+import { useTranslation } from 'react-i18next';
+import { aliasList, removeAlias, setAlias } from '@/services/cmds';
+
+type Props = {
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+};
+
+export const Aliases: React.FC<Props> = ({ open, onOpenChange }) => {
+  const [aliases, setAliases] = useState<Nvmd.Aliases>({});
+  const [name, setName] = useState<string>('');
+  const [version, setVersion] = useState<string>('');
+  const [loading, setLoading] = useState<boolean>(false);
+
+  const { t } = useTranslation();
+
+  const onLoad = async () => {
+    try {
+      setAliases(await aliasList(true));
+    } catch (err) {
+      toast.error(err as string);
+    }
+  };
+
+  useEffect(() => {
+    if (!open) return;
+
+    setName('');
+    setVersion('');
+    onLoad();
+  }, [open]);
+
+  // create the alias or repoint it, the consumers follow the new version
+  const onSet = async (name: string, version: string) => {
+    setLoading(true);
+    try {
+      await setAlias(name, version);
+      toast.success(t('Alias-Saved', { name, version }));
+      setName('');
+      setVersion('');
+      await onLoad();
+    } catch (err) {
+      toast.error(err as string);
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const onRemove = async (name: string) => {
+    try {
+      await removeAlias(name);
+      await onLoad();
+    } catch (err) {
+      toast.error(err as string);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className='top-1/3'>
+        <DialogHeader>
+          <DialogTitle>{t('Aliases')}</DialogTitle>
+          <DialogDescription>{t('Aliases-Tip')}</DialogDescription>
+        </DialogHeader>
+        <div className='max-h-60 space-y-1 overflow-y-auto'>
+          {(aliases.list || []).map(({ name, version }) => (
+            <div
+              key={name}
+              className='flex items-center justify-between text-sm'
+            >
+              <span className='flex items-center gap-2'>
+                <span className='font-medium'>{name}</span>
+                <span className='text-muted-foreground'>→ {version}</span>
+                {aliases.default === name ? (
+                  <Tag color='lime'>{t('Current')}</Tag>
+                ) : null}
+              </span>
+              <Button
+                size='sm'
+                variant='ghost'
+                title={t('Remove')}
+                icon={<TrashIcon />}
+                onClick={() => onRemove(name)}
+              />
+            </div>
+          ))}
+        </div>
+        <div className='flex items-center gap-2'>
+          <Input
+            className='w-32 h-7'
+            placeholder='work'
+            value={name}
+            onChange={(evt) => setName(evt.target.value.trim())}
+          />
+          <span>→</span>
+          <Input
+            className='w-32 h-7'
+            placeholder='18.19.0'
+            value={version}
+            onChange={(evt) => setVersion(evt.target.value.trim())}
+          />
+          <Button
+            size='sm'
+            className='h-7'
+            disabled={!name || !version}
+            loading={loading}
+            icon={<PlusIcon />}
+            onClick={() => onSet(name, version)}
+          >
+            {t('Save')}
+          </Button>
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+};
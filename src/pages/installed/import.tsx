@@ -0,0 +1,173 @@
+import { useEffect, useState } from 'react';
+import {
+  Button,
+  Checkbox,
+  Dialog,
+  DialogClose,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Label,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+  Tag,
+} from '@/components/ui';
+
+import { toast } from 'sonner';
+import { useTranslation } from 'react-i18next';
+import { discoverVersions, importVersions } from '@/services/cmds';
+
+const MODES: Nvmd.ImportMode[] = ['copy', 'hardlink', 'register'];
+
+type Props = {
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  onImported: () => void;
+};
+
+// the key of the installation, the same version may be installed by several tools
+const keyOf = ({ tool, version }: Nvmd.ExternalVersion) => `${tool}@${version}`;
+
+export const Import: React.FC<Props> = ({ open, onOpenChange, onImported }) => {
+  const [list, setList] = useState<Nvmd.ExternalVersion[]>([]);
+  const [selected, setSelected] = useState<string[]>([]);
+  const [mode, setMode] = useState<Nvmd.ImportMode>('copy');
+  const [setDefault, setSetDefault] = useState<boolean>(false);
+  const [loading, setLoading] = useState<boolean>(false);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    if (!open) return;
+
+    setSelected([]);
+    setLoading(true);
+    discoverVersions()
+      .then(setList)
+      .catch((err) => {
+        setList([]);
+        toast.error(err as string);
+      })
+      .finally(() => setLoading(false));
+  }, [open]);
+
+  const onImport = async () => {
+    setLoading(true);
+    try {
+      const results = await importVersions(
+        list.filter((item) => selected.includes(keyOf(item))),
+        mode,
+        setDefault
+      );
+      const failed = results.filter(({ error }) => !!error);
+      failed.length
+        ? toast.error(t('Import-Versions-Failed', { count: failed.length }), {
+            description: failed
+              .map(
+                ({ tool, version, error }) => `${tool} v${version}: ${error}`
+              )
+              .join('\n'),
+          })
+        : toast.success(
+            t('Import-Versions-Successful', { count: results.length })
+          );
+      onImported();
+      onOpenChange(false);
+    } catch (err) {
+      toast.error(err as string);
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className='top-1/3'>
+        <DialogHeader>
+          <DialogTitle>{t('Import-Versions')}</DialogTitle>
+          <DialogDescription>{t('Import-Versions-Tip')}</DialogDescription>
+        </DialogHeader>
+        <div className='max-h-60 space-y-1 overflow-y-auto'>
+          {!loading && !list.length ? (
+            <div className='text-sm text-muted-foreground'>
+              {t('Import-Versions-None')}
+            </div>
+          ) : null}
+          {list.map((item) => {
+            const { tool, version, path, valid, installed, isDefault } = item;
+            const key = keyOf(item);
+            return (
+              <div key={key} className='flex items-center gap-2 text-sm'>
+                <Checkbox
+                  checked={selected.includes(key)}
+                  disabled={!valid || installed}
+                  onCheckedChange={(checked) =>
+                    setSelected((previous) =>
+                      checked
+                        ? [...previous, key]
+                        : previous.filter((value) => value !== key)
+                    )
+                  }
+                />
+                <span className='font-medium'>v{version}</span>
+                <Tag color='sky'>{tool}</Tag>
+                {isDefault ? <Tag color='lime'>{t('Default')}</Tag> : null}
+                {installed ? (
+                  <Tag color='purple'>{t('Installed')}</Tag>
+                ) : !valid ? (
+                  <Tag color='rose'>{t('Invalid')}</Tag>
+                ) : null}
+                <span className='text-muted-foreground truncate' title={path}>
+                  {path}
+                </span>
+              </div>
+            );
+          })}
+        </div>
+        <DialogFooter className='sm:justify-between items-center'>
+          <div className='flex items-center gap-3'>
+            <Select
+              value={mode}
+              onValueChange={(value) => setMode(value as Nvmd.ImportMode)}
+            >
+              <SelectTrigger className='w-28 h-7'>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {MODES.map((mode) => (
+                  <SelectItem key={mode} value={mode}>
+                    {t(`Import-Mode-${mode}`)}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+            <div className='flex items-center gap-2'>
+              <Checkbox
+                checked={setDefault}
+                onCheckedChange={(checked) => setSetDefault(!!checked)}
+              />
+              <Label>{t('Import-Set-Default')}</Label>
+            </div>
+          </div>
+          <div className='flex items-center gap-2'>
+            <DialogClose asChild>
+              <Button variant='secondary'>{t('Cancel')}</Button>
+            </DialogClose>
+            <Button
+              loading={loading}
+              disabled={!selected.length}
+              onClick={onImport}
+            >
+              {t('Import')}
+            </Button>
+          </div>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+};
@@ -22,52 +22,105 @@ import {
   CheckCircledIcon,
   ChevronDownIcon,
   CrossCircledIcon,
+  CubeIcon,
+  DownloadIcon,
+  EnterIcon,
+  Link2Icon,
+  LockClosedIcon,
   MinusCircledIcon,
   ReloadIcon,
+  TrashIcon,
+  UpdateIcon,
 } from '@radix-ui/react-icons';
 
 import dayjs from 'dayjs';
 import { useTranslation } from 'react-i18next';
 import { useAppContext } from '@/app-context';
+import { splitInstallName } from '@/lib/utils';
 import {
-  installedList,
+  installedVersions,
+  installMissing,
+  missingVersions,
+  reinstallNode,
+  securityReport,
   uninstallNode,
   vCurrent,
+  verifyInstalled,
+  versionUsages,
   versionList,
   vSetCurrent,
 } from '@/services/cmds';
 import { getCurrent } from '@/services/api';
+import { Aliases } from './aliases';
+import { Import } from './import';
+import { Packages } from './packages';
+import { Prune } from './prune';
 
-type VersionsResult = [string, Nvmd.Versions, Array<string>];
+type VersionsResult = [string, Nvmd.Versions, Array<Nvmd.InstalledVersion>];
+
+type Row = Nvmd.Version & {
+  /** the installation name, `{version}` or `{version}-{arch}` for a non-default arch */
+  name: string;
+  /** the arch of a side-by-side build */
+  arch?: string;
+};
+
+// one row per installation, the archs of the same version share the release data
+const toRows = (
+  versions: Nvmd.Versions,
+  records: Nvmd.InstalledVersion[]
+): Row[] =>
+  records.map(({ name, version, arch, npm }) => ({
+    version: `v${version}`,
+    npm: npm || '',
+    lts: false,
+    date: '',
+    v8: '',
+    files: [],
+    ...versions.find((item) => item.version === `v${version}`),
+    name,
+    arch: name === version ? undefined : arch || splitInstallName(name)[1],
+  }));
 
 export async function loader() {
   try {
     const versions = await Promise.all([
       vCurrent(),
       versionList(),
-      installedList(),
+      installedVersions(),
     ]);
 
     return versions;
   } catch (err) {
-    return [[], [], ''];
+    return ['', [], []];
   }
 }
 
 export const Component: React.FC = () => {
-  const [currentVersion, allVersions, allInstalledVersions] =
+  const [currentVersion, allVersions, allRecords] =
     useLoaderData() as VersionsResult;
 
   const [current, setCurrent] = useState<string>(() => currentVersion);
-  const [versions, setVersions] = useState<Nvmd.Versions>(() =>
-    allVersions.filter(({ version }) =>
-      allInstalledVersions.includes(version.slice(1))
-    )
-  );
-  const [installedVersions, setInstalledVersions] = useState<string[]>(
-    () => allInstalledVersions
+  const [releases, setReleases] = useState<Nvmd.Versions>(() => allVersions);
+  const [records, setRecords] = useState<Nvmd.InstalledVersion[]>(
+    () => allRecords
   );
   const [loading, setLoading] = useState<boolean>(false);
+  const [verifying, setVerifying] = useState<boolean>(false);
+  const [pruneOpen, setPruneOpen] = useState<boolean>(false);
+  const [importOpen, setImportOpen] = useState<boolean>(false);
+  const [aliasesOpen, setAliasesOpen] = useState<boolean>(false);
+  const [packagesVersion, setPackagesVersion] = useState<string>();
+
+  // the rows & names of the installations
+  const versions = useMemo(
+    () => toRows(releases, records),
+    [releases, records]
+  );
+  const installedNames = useMemo(
+    () => records.map(({ name }) => name),
+    [records]
+  );
 
   const { settings } = useAppContext();
   const { directory } = settings;
@@ -89,20 +142,25 @@ export const Component: React.FC = () => {
     };
   }, []);
 
+  useEffect(() => {
+    const unlisted = getCurrent().listen<string[]>(
+      'call-installed-update',
+      async () => setRecords(await installedVersions())
+    );
+
+    return () => {
+      unlisted.then((fn) => fn());
+    };
+  }, []);
+
   useEffect(() => {
     const fetcher = async () => {
-      const iVersions = await installedList(false);
-      setVersions(
-        allVersions.filter(({ version }) =>
-          iVersions.includes(version.slice(1))
-        )
-      );
-      setInstalledVersions(iVersions);
+      setRecords(await installedVersions(false));
     };
     fetcher();
   }, [directory]);
 
-  const columns: ColumnDef<Nvmd.Version>[] = useMemo(() => {
+  const columns: ColumnDef<Row>[] = useMemo(() => {
     const { version: latest } = versions[0] || { version: '' };
     return [
       {
@@ -127,7 +185,7 @@ export const Component: React.FC = () => {
           );
         },
         cell: ({ row }) => {
-          const { version, lts } = row.original;
+          const { version, lts, arch } = row.original;
           return (
             <div className='flex gap-1 items-center'>
               <Tooltip>
@@ -151,6 +209,7 @@ export const Component: React.FC = () => {
                   ({t('latest')})
                 </span>
               ) : null}
+              {arch ? <Tag color='sky'>{arch}</Tag> : null}
             </div>
           );
         },
@@ -192,7 +251,8 @@ export const Component: React.FC = () => {
         meta: {
           label: t('Release-Date'),
         },
-        cell: ({ row }) => dayjs(row.original.date).format('ll'),
+        cell: ({ row }) =>
+          row.original.date ? dayjs(row.original.date).format('ll') : null,
       },
       {
         accessorKey: 'status',
@@ -202,17 +262,15 @@ export const Component: React.FC = () => {
         },
         enableSorting: false,
         filterFn: (row, _columnId, filterValue: string[]) => {
-          const { version } = row.original;
+          const { name } = row.original;
 
           const rets = filterValue.map((value) => {
             switch (value) {
               case 'Current': {
-                return version.includes(current);
+                return name === current;
               }
               case 'Installed': {
-                return !!installedVersions.find((installed) =>
-                  version.includes(installed)
-                );
+                return true;
               }
               default:
                 return false;
@@ -222,18 +280,12 @@ export const Component: React.FC = () => {
           return rets.includes(true);
         },
         cell: ({ row }) => {
-          const { version } = row.original;
+          const { name } = row.original;
 
-          const installed = installedVersions.find((installed) =>
-            version.includes(installed)
-          );
-
-          if (installed && current && version.includes(current))
+          if (current && name === current)
             return <Tag color='lime'>{t('Current')}</Tag>;
 
-          if (installed) return <Tag color='purple'>{t('Installed')}</Tag>;
-
-          return <Tag color='neutral'>{t('Not-Installed')}</Tag>;
+          return <Tag color='purple'>{t('Installed')}</Tag>;
         },
       },
       {
@@ -241,7 +293,8 @@ export const Component: React.FC = () => {
         enableHiding: false,
         enableSorting: false,
         cell: ({ row }) => {
-          const { version } = row.original;
+          const { name } = row.original;
+          const version = `v${name}`;
           return (
             <DropdownMenu>
               <DropdownMenuTrigger asChild>
@@ -259,9 +312,8 @@ export const Component: React.FC = () => {
                   className='flex gap-2 cursor-pointer'
                   onSelect={async () => {
                     try {
-                      const curVersion = version.slice(1);
-                      await vSetCurrent(curVersion);
-                      setCurrent(curVersion);
+                      await vSetCurrent(name);
+                      setCurrent(name);
                       toast.success(t('Restart-Terminal', { version }));
                     } catch (err) {
                       toast.error(err);
@@ -271,29 +323,56 @@ export const Component: React.FC = () => {
                   <CheckCircledIcon />
                   {t('Apply')}
                 </DropdownMenuItem>
+                <DropdownMenuItem
+                  className='flex gap-2 cursor-pointer'
+                  onSelect={() => onReinstall([name])}
+                >
+                  <UpdateIcon />
+                  {t('Reinstall')}
+                </DropdownMenuItem>
+                <DropdownMenuItem
+                  className='flex gap-2 cursor-pointer'
+                  onSelect={() => setPackagesVersion(name)}
+                >
+                  <CubeIcon />
+                  {t('Global-Packages')}
+                </DropdownMenuItem>
                 <DropdownMenuItem
                   className='flex gap-2 text-red-600 focus:text-red-500 cursor-pointer'
                   onSelect={async () => {
-                    try {
-                      await uninstallNode(
-                        version.slice(1),
-                        version.includes(current)
-                      );
-                      const [currentVersion, installeds] = await Promise.all([
-                        vCurrent(),
-                        installedList(true),
-                      ]);
-                      setCurrent(currentVersion);
-                      setInstalledVersions(installeds);
-                      setVersions(
-                        allVersions.filter(({ version }) =>
-                          installeds.includes(version.slice(1))
-                        )
+                    const onUninstall = async (force: boolean) => {
+                      try {
+                        await uninstallNode(name, name === current, force);
+                        const [currentVersion, records] = await Promise.all([
+                          vCurrent(),
+                          installedVersions(true),
+                        ]);
+                        setCurrent(currentVersion);
+                        setRecords(records);
+                        toast.success(t('Tip-Uninstall', { version }));
+                      } catch (err) {
+                        toast.error(err);
+                      }
+                    };
+
+                    // confirm before uninstalling a version still in use
+                    const usages = await versionUsages(name).catch(() => []);
+                    if (usages.length) {
+                      toast.warning(
+                        t('Tip-Uninstall-Used', {
+                          version,
+                          usages: usages.map(({ name }) => name).join(', '),
+                        }),
+                        {
+                          action: {
+                            label: t('Uninstall'),
+                            onClick: () => onUninstall(true),
+                          },
+                        }
                       );
-                      toast.success(t('Tip-Uninstall', { version }));
-                    } catch (err) {
-                      toast.error(err);
+                      return;
                     }
+                    onUninstall(false);
                   }}
                 >
                   <CrossCircledIcon />
@@ -305,7 +384,7 @@ export const Component: React.FC = () => {
         },
       },
     ];
-  }, [t, current, installedVersions.length]);
+  }, [t, current, installedNames.length]);
 
   const statuses = useMemo(
     () => [
@@ -324,7 +403,7 @@ export const Component: React.FC = () => {
   );
 
   const getFacetedUniqueValues: () => (
-    table: Table<Nvmd.Version>,
+    table: Table<Row>,
     columnId: string
   ) => () => Map<any, number> = useMemo(() => {
     return function getFacetedUniqueValues() {
@@ -337,10 +416,10 @@ export const Component: React.FC = () => {
             let facetedUniqueValues = new Map<any, number>();
 
             for (let i = 0; i < facetedRowModel.flatRows.length; i++) {
-              const { version } = facetedRowModel.flatRows[i]!.original;
+              const { name } = facetedRowModel.flatRows[i]!.original;
 
               let key: string = 'Installed';
-              if (version.includes(current)) key = 'Current';
+              if (name === current) key = 'Current';
 
               if (facetedUniqueValues.has(key)) {
                 facetedUniqueValues.set(
@@ -363,21 +442,19 @@ export const Component: React.FC = () => {
           }
         );
     };
-  }, [current, installedVersions.length]);
+  }, [current, installedNames.length]);
 
   const onPageReload = async () => {
     setLoading(true);
     try {
-      const [currentVersion, versions, installeds] = await Promise.all([
+      const [currentVersion, versions, records] = await Promise.all([
         vCurrent(true),
         versionList(),
-        installedList(true),
+        installedVersions(true),
       ]);
       setCurrent(currentVersion);
-      setVersions(
-        versions.filter(({ version }) => installeds.includes(version.slice(1)))
-      );
-      setInstalledVersions(installeds);
+      setReleases(versions);
+      setRecords(records);
       toast.success(t('Refresh-successful'));
     } catch (err) {
       toast.error(err);
@@ -386,6 +463,134 @@ export const Component: React.FC = () => {
     }
   };
 
+  // reinstall the versions one by one, the old files are restored when it fails
+  const onReinstall = async (names: string[]) => {
+    for (const name of names) {
+      const version = `v${name}`;
+      const toastId = toast.loading(t('Reinstalling', { version }));
+      try {
+        await reinstallNode(name);
+        toast.success(t('Reinstall-Successful', { version }), { id: toastId });
+      } catch (err) {
+        toast.error(`${version}: ${err}`, { id: toastId });
+      }
+    }
+  };
+
+  // check the files of the installed versions against the recorded checksums
+  const onVerify = async () => {
+    setVerifying(true);
+    try {
+      const results = await verifyInstalled();
+      const damaged = results.filter(
+        ({ status }) => status === 'corrupt' || status === 'missing'
+      );
+      if (!damaged.length) {
+        toast.success(t('Verify-Passed'));
+        return;
+      }
+
+      toast.error(t('Verify-Failed', { count: damaged.length }), {
+        description: damaged
+          .map(({ version, modified, missing, message }) => {
+            const details = [
+              modified.length &&
+                t('Verify-Modified', { count: modified.length }),
+              missing.length && t('Verify-Missing', { count: missing.length }),
+              message,
+            ].filter(Boolean);
+            return `v${version}: ${details.join(', ')}`;
+          })
+          .join('\n'),
+        duration: Infinity,
+        action: {
+          label: t('Reinstall'),
+          onClick: () => onReinstall(damaged.map(({ version }) => version)),
+        },
+      });
+    } catch (err) {
+      toast.error(err as string);
+    } finally {
+      setVerifying(false);
+    }
+  };
+
+  // check the versions in use for newer security releases
+  const onSecurityCheck = async () => {
+    try {
+      const alerts = await securityReport();
+      if (!alerts.length) {
+        toast.success(t('Security-None'));
+        return;
+      }
+
+      alerts.forEach(({ version, securityVersion, sources }) => {
+        toast.warning(t('Security-Alert', { version, securityVersion }), {
+          description: t('Security-Alert-Sources', {
+            sources: sources.join(', '),
+          }),
+          duration: 10000,
+        });
+      });
+    } catch (err) {
+      toast.error(err as string);
+    }
+  };
+
+  // install the versions used by the default, projects & groups
+  const onInstallMissing = async () => {
+    const missing = await missingVersions();
+    if (!missing.length) {
+      toast.success(t('Missing-Versions-None'));
+      return;
+    }
+
+    toast.info(t('Missing-Versions-Found', { count: missing.length }), {
+      description: missing
+        .map(
+          ({ name, usages }) =>
+            `v${name}: ${usages.map(({ name }) => name).join(', ')}`
+        )
+        .join('\n'),
+      duration: Infinity,
+      action: {
+        label: t('Install'),
+        onClick: async () => {
+          const toastId = toast.loading(t('Missing-Versions-Installing'));
+          const unlisten = await getCurrent().listen<Nvmd.RequiredProgress>(
+            'on-required-progress',
+            ({ payload: { name, current, total } }) => {
+              toast.loading(`v${name} (${current}/${total})`, { id: toastId });
+            }
+          );
+          try {
+            const results = await installMissing();
+            const failed = results.filter(({ error }) => !!error);
+            failed.length
+              ? toast.error(
+                  t('Missing-Versions-Failed', { count: failed.length }),
+                  {
+                    id: toastId,
+                    description: failed
+                      .map(({ name, error }) => `v${name}: ${error}`)
+                      .join('\n'),
+                  }
+                )
+              : toast.success(
+                  t('Missing-Versions-Installed', { count: results.length }),
+                  { id: toastId }
+                );
+            onPageReload();
+          } catch (err) {
+            toast.error(err as string, { id: toastId });
+          } finally {
+            unlisten();
+          }
+        },
+      },
+    });
+  };
+
   return (
     <div className='h-full flex flex-col space-y-2'>
       <DataTable
@@ -404,10 +609,75 @@ export const Component: React.FC = () => {
             >
               {t('Page-Reload')}
             </Button>
+            <Button
+              size='sm'
+              className='h-7 text-sm'
+              icon={<DownloadIcon />}
+              onClick={onInstallMissing}
+            >
+              {t('Missing-Versions')}
+            </Button>
+            <Button
+              size='sm'
+              className='h-7 text-sm'
+              loading={verifying}
+              icon={<CheckCircledIcon />}
+              onClick={onVerify}
+            >
+              {t('Verify')}
+            </Button>
+            <Button
+              size='sm'
+              className='h-7 text-sm'
+              icon={<LockClosedIcon />}
+              onClick={onSecurityCheck}
+            >
+              {t('Security-Check')}
+            </Button>
+            <Button
+              size='sm'
+              className='h-7 text-sm'
+              icon={<EnterIcon />}
+              onClick={() => setImportOpen(true)}
+            >
+              {t('Import')}
+            </Button>
+            <Button
+              size='sm'
+              className='h-7 text-sm'
+              icon={<Link2Icon />}
+              onClick={() => setAliasesOpen(true)}
+            >
+              {t('Aliases')}
+            </Button>
+            <Button
+              size='sm'
+              className='h-7 text-sm'
+              icon={<TrashIcon />}
+              onClick={() => setPruneOpen(true)}
+            >
+              {t('Prune')}
+            </Button>
           </div>
         )}
         getFacetedUniqueValues={getFacetedUniqueValues}
       />
+      <Prune
+        open={pruneOpen}
+        onOpenChange={setPruneOpen}
+        onPruned={onPageReload}
+      />
+      <Aliases open={aliasesOpen} onOpenChange={setAliasesOpen} />
+      <Import
+        open={importOpen}
+        onOpenChange={setImportOpen}
+        onImported={onPageReload}
+      />
+      <Packages
+        version={packagesVersion}
+        installed={installedNames}
+        onOpenChange={(open) => !open && setPackagesVersion(undefined)}
+      />
     </div>
   );
 };
@@ -0,0 +1,206 @@
+import { useEffect, useState } from 'react';
+import {
+  Button,
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+  Tag,
+} from '@/components/ui';
+import { TrashIcon, UpdateIcon } from '@radix-ui/react-icons';
+
+import { toast } from 'sonner';
+import { useTranslation } from 'react-i18next';
+import { getCurrent } from '@/services/api';
+import {
+  globalPackages,
+  migratePackages,
+  uninstallPackage,
+  updatePackage,
+} from '@/services/cmds';
+
+type Props = {
+  // the installation name of the version, `undefined` to close
+  version?: string;
+  // the installed versions the packages can be migrated to
+  installed: string[];
+  onOpenChange: (open: boolean) => void;
+};
+
+export const Packages: React.FC<Props> = ({
+  version,
+  installed,
+  onOpenChange,
+}) => {
+  const [packages, setPackages] = useState<Nvmd.GlobalPackage[]>([]);
+  const [loading, setLoading] = useState<boolean>(false);
+  const [migrateTo, setMigrateTo] = useState<string>();
+  const [migrating, setMigrating] = useState<boolean>(false);
+  // the package being updated or uninstalled
+  const [pending, setPending] = useState<string>();
+
+  const { t } = useTranslation();
+
+  const onLoad = async (version: string) => {
+    setLoading(true);
+    try {
+      setPackages(await globalPackages(version));
+    } catch (err) {
+      setPackages([]);
+      toast.error(err as string);
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  useEffect(() => {
+    setMigrateTo(undefined);
+    version && onLoad(version);
+  }, [version]);
+
+  const onAction = async (name: string, action: 'update' | 'uninstall') => {
+    if (!version) return;
+
+    setPending(name);
+    try {
+      action === 'update'
+        ? await updatePackage(version, name)
+        : await uninstallPackage(version, name);
+      toast.success(
+        t(action === 'update' ? 'Package-Updated' : 'Package-Uninstalled', {
+          name,
+        })
+      );
+      await onLoad(version);
+    } catch (err) {
+      toast.error(`${name}: ${err}`);
+    } finally {
+      setPending(undefined);
+    }
+  };
+
+  // reinstall the global packages into the target version with its npm
+  const onMigrate = async () => {
+    if (!version || !migrateTo) return;
+
+    setMigrating(true);
+    const toastId = toast.loading(t('Migrating'));
+    const unlisten = await getCurrent().listen<Nvmd.PackageProgress>(
+      'on-packages-progress',
+      ({ payload: { name, current, total } }) => {
+        toast.loading(`${name} (${current}/${total})`, { id: toastId });
+      }
+    );
+    try {
+      const results = await migratePackages(version, migrateTo);
+      const failed = results.filter(({ error }) => !!error);
+      failed.length
+        ? toast.error(t('Packages-Failed', { count: failed.length }), {
+            id: toastId,
+            description: failed
+              .map(({ name, error }) => `${name}: ${error}`)
+              .join('\n'),
+          })
+        : toast.success(
+            t('Packages-Installed', {
+              count: results.filter(({ skipped }) => !skipped).length,
+            }),
+            { id: toastId }
+          );
+    } catch (err) {
+      toast.error(err as string, { id: toastId });
+    } finally {
+      unlisten();
+      setMigrating(false);
+    }
+  };
+
+  return (
+    <Dialog open={!!version} onOpenChange={onOpenChange}>
+      <DialogContent className='top-1/3'>
+        <DialogHeader>
+          <DialogTitle>{t('Global-Packages')}</DialogTitle>
+          <DialogDescription>v{version}</DialogDescription>
+        </DialogHeader>
+        <div className='max-h-72 space-y-1 overflow-y-auto'>
+          {!loading && !packages.length ? (
+            <div className='text-sm text-muted-foreground'>
+              {t('Global-Packages-None')}
+            </div>
+          ) : null}
+          {packages.map(({ name, version: pVersion, linked, bins }) => (
+            <div
+              key={name}
+              className='flex items-center justify-between gap-2 text-sm'
+            >
+              <span className='flex items-center gap-2 truncate'>
+                <span className='font-medium'>{name}</span>
+                <span className='text-muted-foreground'>{pVersion}</span>
+                {linked ? <Tag color='sky'>{t('Linked')}</Tag> : null}
+                {bins.length ? (
+                  <span
+                    className='text-muted-foreground truncate'
+                    title={bins.join(', ')}
+                  >
+                    ({bins.join(', ')})
+                  </span>
+                ) : null}
+              </span>
+              <span className='flex items-center gap-1'>
+                <Button
+                  size='sm'
+                  variant='ghost'
+                  title={t('Update')}
+                  disabled={linked || !!pending}
+                  loading={pending === name}
+                  icon={<UpdateIcon />}
+                  onClick={() => onAction(name, 'update')}
+                />
+                <Button
+                  size='sm'
+                  variant='ghost'
+                  title={t('Uninstall')}
+                  disabled={!!pending}
+                  icon={<TrashIcon />}
+                  onClick={() => onAction(name, 'uninstall')}
+                />
+              </span>
+            </div>
+          ))}
+        </div>
+        <DialogFooter className='sm:justify-start items-center gap-2'>
+          <Select value={migrateTo} onValueChange={setMigrateTo}>
+            <SelectTrigger className='w-40 h-7'>
+              <SelectValue placeholder={t('Migrate-To')} />
+            </SelectTrigger>
+            <SelectContent>
+              {installed
+                .filter((item) => item !== version)
+                .map((item) => (
+                  <SelectItem key={item} value={item}>
+                    v{item}
+                  </SelectItem>
+                ))}
+            </SelectContent>
+          </Select>
+          <Button
+            size='sm'
+            className='h-7'
+            disabled={!migrateTo || !packages.length}
+            loading={migrating}
+            onClick={onMigrate}
+          >
+            {t('Migrate')}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+};
@@ -0,0 +1,156 @@
+import { useEffect, useState } from 'react';
+import {
+  Button,
+  Checkbox,
+  Dialog,
+  DialogClose,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Label,
+  Tag,
+} from '@/components/ui';
+
+import { toast } from 'sonner';
+import { useTranslation } from 'react-i18next';
+import { formatSize } from '@/lib/utils';
+import { pruneVersions } from '@/services/cmds';
+
+type Props = {
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  onPruned: () => void;
+};
+
+export const Prune: React.FC<Props> = ({ open, onOpenChange, onPruned }) => {
+  const [unused, setUnused] = useState<boolean>(true);
+  const [keepLatest, setKeepLatest] = useState<string>('');
+  // the dry run report of the current options
+  const [report, setReport] = useState<Nvmd.PruneReport>();
+  const [loading, setLoading] = useState<boolean>(false);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    setReport(undefined);
+  }, [open, unused, keepLatest]);
+
+  const options = (dryRun: boolean): Nvmd.PruneOptions => ({
+    unused,
+    keepLatest: /^\d+$/.test(keepLatest) ? Number(keepLatest) : undefined,
+    dryRun,
+  });
+
+  const onPreview = async () => {
+    setLoading(true);
+    try {
+      setReport(await pruneVersions(options(true)));
+    } catch (err) {
+      toast.error(err as string);
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const onPrune = async () => {
+    setLoading(true);
+    try {
+      const { candidates, reclaimable } = await pruneVersions(options(false));
+      const failed = candidates.filter(({ error }) => !!error);
+      failed.length
+        ? toast.error(t('Prune-Failed', { count: failed.length }), {
+            description: failed
+              .map(({ version, error }) => `v${version}: ${error}`)
+              .join('\n'),
+          })
+        : toast.success(
+            t('Prune-Successful', {
+              count: candidates.length,
+              size: formatSize(reclaimable),
+            })
+          );
+      onPruned();
+      onOpenChange(false);
+    } catch (err) {
+      toast.error(err as string);
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className='top-1/3'>
+        <DialogHeader>
+          <DialogTitle>{t('Prune')}</DialogTitle>
+          <DialogDescription>{t('Prune-Tip')}</DialogDescription>
+        </DialogHeader>
+        <div className='space-y-4'>
+          <div className='flex items-center gap-2'>
+            <Checkbox
+              checked={unused}
+              onCheckedChange={(checked) => setUnused(!!checked)}
+            />
+            <Label>{t('Prune-Unused')}</Label>
+          </div>
+          <div className='flex items-center gap-2'>
+            <Label>{t('Prune-Keep-Latest')}</Label>
+            <Input
+              className='w-16 h-7 text-center'
+              value={keepLatest}
+              onChange={(evt) => setKeepLatest(evt.target.value.trim())}
+            />
+          </div>
+          {report ? (
+            report.candidates.length ? (
+              <div className='max-h-48 space-y-1 overflow-y-auto'>
+                {report.candidates.map(({ version, reason, size }) => (
+                  <div
+                    key={version}
+                    className='flex items-center justify-between text-sm'
+                  >
+                    <span className='flex items-center gap-2'>
+                      v{version}
+                      <Tag color={reason === 'unused' ? 'neutral' : 'purple'}>
+                        {t(`Prune-${reason}`)}
+                      </Tag>
+                    </span>
+                    <span className='text-muted-foreground'>
+                      {formatSize(size)}
+                    </span>
+                  </div>
+                ))}
+                <div className='pt-1 text-sm font-medium'>
+                  {t('Prune-Reclaimable', {
+                    size: formatSize(report.reclaimable),
+                  })}
+                </div>
+              </div>
+            ) : (
+              <div className='text-sm text-muted-foreground'>
+                {t('Prune-None')}
+              </div>
+            )
+          ) : null}
+        </div>
+        <DialogFooter>
+          <DialogClose asChild>
+            <Button variant='secondary'>{t('Cancel')}</Button>
+          </DialogClose>
+          {report?.candidates.length ? (
+            <Button variant='destructive' loading={loading} onClick={onPrune}>
+              {t('Prune')}
+            </Button>
+          ) : (
+            <Button loading={loading} onClick={onPreview}>
+              {t('Prune-Preview')}
+            </Button>
+          )}
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+};
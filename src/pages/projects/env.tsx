@@ -0,0 +1,133 @@
+import { useEffect, useState } from 'react';
+import {
+  Button,
+  Dialog,
+  DialogClose,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Label,
+  Switch,
+} from '@/components/ui';
+import { Cross2Icon, PlusIcon } from '@radix-ui/react-icons';
+
+import { toast } from 'sonner';
+import { useTranslation } from 'react-i18next';
+import { updateProjectEnv } from '@/services/cmds';
+
+type Props = {
+  project?: Nvmd.Project;
+  onOpenChange: (open: boolean) => void;
+  onSubmit: (project: Nvmd.Project) => void;
+};
+
+export const Env: React.FC<Props> = ({ project, onOpenChange, onSubmit }) => {
+  // the entries are kept in order while editing, the empty names are dropped when saved
+  const [entries, setEntries] = useState<Array<[string, string]>>([]);
+  const [envFile, setEnvFile] = useState<boolean>(false);
+  const [loading, setLoading] = useState<boolean>(false);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    setEntries(Object.entries(project?.env || {}));
+    setEnvFile(!!project?.envFile);
+  }, [project]);
+
+  const onChange = (index: number, entry: [string, string]) =>
+    setEntries((previous) =>
+      previous.map((item, idx) => (idx === index ? entry : item))
+    );
+
+  const onSave = async () => {
+    if (!project?.id) return;
+
+    const env = Object.fromEntries(
+      entries
+        .map(([name, value]): [string, string] => [name.trim(), value])
+        .filter(([name]) => !!name)
+    );
+    setLoading(true);
+    try {
+      await updateProjectEnv(project.id, env, envFile);
+      onSubmit({
+        ...project,
+        env: Object.keys(env).length ? env : undefined,
+        envFile,
+      });
+      onOpenChange(false);
+    } catch (err) {
+      toast.error(err as string);
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  return (
+    <Dialog open={!!project} onOpenChange={onOpenChange}>
+      <DialogContent className='top-1/3'>
+        <DialogHeader>
+          <DialogTitle>{t('Env')}</DialogTitle>
+          <DialogDescription>{t('Env-Tip')}</DialogDescription>
+        </DialogHeader>
+        <div className='space-y-3'>
+          <div className='max-h-60 space-y-2 overflow-y-auto'>
+            {entries.map(([name, value], index) => (
+              <div key={index} className='flex items-center gap-2'>
+                <Input
+                  className='w-40 h-7'
+                  placeholder='NODE_OPTIONS'
+                  value={name}
+                  onChange={(evt) =>
+                    onChange(index, [evt.target.value, value])
+                  }
+                />
+                <span>=</span>
+                <Input
+                  className='flex-1 h-7'
+                  value={value}
+                  onChange={(evt) =>
+                    onChange(index, [name, evt.target.value])
+                  }
+                />
+                <Button
+                  size='sm'
+                  variant='ghost'
+                  icon={<Cross2Icon />}
+                  onClick={() =>
+                    setEntries((previous) =>
+                      previous.filter((_, idx) => idx !== index)
+                    )
+                  }
+                />
+              </div>
+            ))}
+          </div>
+          <Button
+            size='sm'
+            variant='secondary'
+            icon={<PlusIcon />}
+            onClick={() => setEntries((previous) => [...previous, ['', '']])}
+          >
+            {t('Env-Add')}
+          </Button>
+          <div className='flex items-center gap-3'>
+            <Switch checked={envFile} onCheckedChange={setEnvFile} />
+            <Label>{t('Env-File')}</Label>
+          </div>
+        </div>
+        <DialogFooter>
+          <DialogClose asChild>
+            <Button variant='secondary'>{t('Cancel')}</Button>
+          </DialogClose>
+          <Button loading={loading} onClick={onSave}>
+            {t('OK')}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+};
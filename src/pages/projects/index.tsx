@@ -14,6 +14,10 @@ import {
   Button,
   DataDndTable,
   DataTableToolbar,
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuTrigger,
   LabelCopyable,
   Select,
   SelectContent,
@@ -25,7 +29,20 @@ import {
 } from '@/components/ui';
 import { DndProvider } from 'react-dnd';
 import { HTML5Backend } from 'react-dnd-html5-backend';
-import { FilePlusIcon, ReloadIcon, TrashIcon } from '@radix-ui/react-icons';
+import {
+  ChevronDownIcon,
+  CodeIcon,
+  DesktopIcon,
+  ExclamationTriangleIcon,
+  FileIcon,
+  FilePlusIcon,
+  GearIcon,
+  MagnifyingGlassIcon,
+  MixIcon,
+  PlayIcon,
+  ReloadIcon,
+  TrashIcon,
+} from '@radix-ui/react-icons';
 import { toast } from 'sonner';
 
 import { cn } from '@/lib/utils';
@@ -33,40 +50,62 @@ import { useAppContext } from '@/app-context';
 import { useTranslation } from 'react-i18next';
 import {
   selectProjects,
+  detectProjectVersion,
+  scanProjects,
+  importProjects,
+  projectHealth,
+  fixProjectHealth,
   groupList,
   installedList,
+  aliasList,
   projectList,
   updateGroups,
   updateProjects,
   syncProjectVersion,
   openDir,
+  openTerminal,
+  openEditor,
 } from '@/services/cmds';
 import { getCurrent } from '@/services/api';
 import type { ColumnDef } from '@tanstack/react-table';
+import { Env } from './env';
+import { Scripts } from './scripts';
+import { VersionFiles } from './version-files';
 
 export async function loader() {
   const versions = await Promise.all([
     projectList(),
     groupList(),
     installedList(),
+    aliasList(),
   ]);
 
   return versions;
 }
 
 export const Component: React.FC = () => {
-  const [allProjects, allGroups, allInstalledVersions] = useLoaderData() as [
-    Nvmd.Project[],
-    Nvmd.Group[],
-    Array<string>
-  ];
+  const [allProjects, allGroups, allInstalledVersions, allAliases] =
+    useLoaderData() as [
+      Nvmd.Project[],
+      Nvmd.Group[],
+      Array<string>,
+      Nvmd.Aliases
+    ];
 
   const [installedVersions, setInstalledVersions] = useState<string[]>(
     () => allInstalledVersions
   );
   const [projects, setProjects] = useState<Nvmd.Project[]>(() => allProjects);
   const [groups, setGroups] = useState<Nvmd.Group[]>(() => allGroups);
+  const [aliases, setAliases] = useState<Nvmd.Aliases>(() => allAliases);
   const [loading, setLoading] = useState<boolean>(false);
+  // the project whose version files are edited
+  const [versionFilesProject, setVersionFilesProject] =
+    useState<Nvmd.Project>();
+  // the project whose scripts are run
+  const [scriptsProject, setScriptsProject] = useState<Nvmd.Project>();
+  // the project whose environment variables are edited
+  const [envProject, setEnvProject] = useState<Nvmd.Project>();
 
   const { t } = useTranslation();
   const { settings } = useAppContext();
@@ -101,6 +140,101 @@ export const Component: React.FC = () => {
     fetcher();
   }, [directory]);
 
+  // switch the project to the version or the group
+  const onVersionChange = async (
+    { id, version, path }: Nvmd.Project,
+    newVersion: string
+  ) => {
+    // fromGroup: whether to switch from group, need to remove
+    // toGroup: whether to switch to group, need to add
+    const fromGroup = groups.find(({ name }) => name === version),
+      toGroup = groups.find(({ name }) => name === newVersion);
+    try {
+      const targetVersion = toGroup ? toGroup.version : newVersion || '';
+      const code = await syncProjectVersion(path, targetVersion);
+
+      const updateProjectsPromise = async () => {
+        const newProjects = projects.map((project) =>
+          project.path === path
+            ? {
+                ...project,
+                version: toGroup ? toGroup.name : newVersion ? newVersion : '',
+                active: code === 200 ? true : false,
+                updateAt: new Date().toISOString(),
+              }
+            : project
+        );
+        await updateProjects(newProjects);
+
+        return newProjects;
+      };
+
+      const updateGroupsPromise = async () => {
+        const newGroups = [...groups];
+        let needUpdate: boolean = false;
+        newGroups.forEach((group) => {
+          const groupProjects = [...group.projects];
+          if (fromGroup && group.name === version) {
+            needUpdate = true;
+            group.projects = groupProjects.filter((project) => project !== id);
+          }
+
+          if (toGroup && group.name === newVersion) {
+            needUpdate = true;
+            group.projects = [id!].concat(groupProjects);
+          }
+        });
+
+        if (!needUpdate) return Promise.resolve(undefined);
+
+        await updateGroups(newGroups);
+        return newGroups;
+      };
+
+      const [newProjects, newGroups] = await Promise.all([
+        updateProjectsPromise(),
+        updateGroupsPromise(),
+      ]);
+
+      setProjects(newProjects);
+      newGroups && setGroups(newGroups);
+      code === 200
+        ? toast.success(
+            t('Restart-Terminal', { version: `v${targetVersion}` })
+          )
+        : toast.error(`Project not found, please check it`);
+    } catch (err) {
+      toast.error('Something went wrong');
+    }
+  };
+
+  // detect the version from the version files, the installed version matched can be applied
+  const onDetectVersion = async (project: Nvmd.Project) => {
+    try {
+      const detected = await detectProjectVersion(project.path);
+      if (!detected) {
+        toast.info(t('Detect-None'));
+        return;
+      }
+
+      const { source, spec, version } = detected;
+      toast.info(t('Detect-Found', { source, spec }), {
+        description: version
+          ? t('Detect-Resolved', { version })
+          : t('Detect-Unresolved'),
+        action:
+          version && version !== project.version
+            ? {
+                label: t('Apply'),
+                onClick: () => onVersionChange(project, version),
+              }
+            : undefined,
+      });
+    } catch (err) {
+      toast.error(err as string);
+    }
+  };
+
   const columns: ColumnDef<Nvmd.Project>[] = useMemo(
     () => [
       {
@@ -155,81 +289,14 @@ export const Component: React.FC = () => {
         },
         maxSize: 200,
         cell: ({ row }) => {
-          const { version, path } = row.original;
+          const { version } = row.original;
           return (
             <Select
+              key={version}
               defaultValue={version}
-              onValueChange={async (newVersion) => {
-                // fromGroup: whether to switch from group, need to remove
-                // toGroup: whether to switch to group, need to add
-                const fromGroup = groups.find(({ name }) => name === version),
-                  toGroup = groups.find(({ name }) => name === newVersion);
-                try {
-                  const targetVersion = toGroup
-                    ? toGroup.version
-                    : newVersion || '';
-                  const code = await syncProjectVersion(path, targetVersion);
-
-                  const updateProjectsPromise = async () => {
-                    const newProjects = projects.map((project) =>
-                      project.path === path
-                        ? {
-                            ...project,
-                            version: toGroup
-                              ? toGroup.name
-                              : newVersion
-                              ? newVersion
-                              : '',
-                            active: code === 200 ? true : false,
-                            updateAt: new Date().toISOString(),
-                          }
-                        : project
-                    );
-                    await updateProjects(newProjects);
-
-                    return newProjects;
-                  };
-
-                  const updateGroupsPromise = async () => {
-                    const newGroups = [...groups];
-                    let needUpdate: boolean = false;
-                    newGroups.forEach((group) => {
-                      const groupProjects = [...group.projects];
-                      if (fromGroup && group.name === version) {
-                        needUpdate = true;
-                        group.projects = groupProjects.filter(
-                          (project) => project !== path
-                        );
-                      }
-
-                      if (toGroup && group.name === newVersion) {
-                        needUpdate = true;
-                        group.projects = [path].concat(groupProjects);
-                      }
-                    });
-
-                    if (!needUpdate) return Promise.resolve(undefined);
-
-                    await updateGroups(newGroups);
-                    return newGroups;
-                  };
-
-                  const [newProjects, newGroups] = await Promise.all([
-                    updateProjectsPromise(),
-                    updateGroupsPromise(),
-                  ]);
-
-                  setProjects(newProjects);
-                  newGroups && setGroups(newGroups);
-                  code === 200
-                    ? toast.success(
-                        t('Restart-Terminal', { version: `v${targetVersion}` })
-                      )
-                    : toast.error(`Project not found, please check it`);
-                } catch (err) {
-                  toast.error('Something went wrong');
-                }
-              }}
+              onValueChange={(newVersion) =>
+                onVersionChange(row.original, newVersion)
+              }
             >
               <SelectTrigger className='h-6'>
                 <SelectValue />
@@ -245,6 +312,22 @@ export const Component: React.FC = () => {
                     </SelectItem>
                   ))}
                 </SelectGroup>
+                {aliases.list?.length ? (
+                  <SelectGroup>
+                    <SelectLabel className='text-muted-foreground'>
+                      {t('Aliases')}
+                    </SelectLabel>
+                    {aliases.list.map(({ name, version }) => (
+                      <SelectItem
+                        key={name}
+                        value={name}
+                        title={`${name} (v${version})`}
+                      >
+                        {name}
+                      </SelectItem>
+                    ))}
+                  </SelectGroup>
+                ) : null}
                 <SelectGroup>
                   <SelectLabel className='text-muted-foreground'>
                     {t('Groups')}
@@ -266,66 +349,139 @@ export const Component: React.FC = () => {
       },
       {
         header: t('Operation'),
-        maxSize: 120,
+        maxSize: 260,
         cell: ({ row }) => {
-          const { name, path, version } = row.original;
+          const { id, name, path, version } = row.original;
           return (
-            <AlertDialog>
-              <AlertDialogTrigger asChild>
-                <Button size='sm' variant='tag'>
-                  <TrashIcon />
-                  {t('Remove')}
-                </Button>
-              </AlertDialogTrigger>
-              <AlertDialogContent className='top-1/3'>
-                <AlertDialogHeader>
-                  <AlertDialogTitle>{name}</AlertDialogTitle>
-                  <AlertDialogDescription>
-                    {t('Project-Delete')}
-                  </AlertDialogDescription>
-                </AlertDialogHeader>
-                <AlertDialogFooter>
-                  <AlertDialogCancel>{t('Cancel')}</AlertDialogCancel>
-                  <AlertDialogAction
-                    onClick={async () => {
-                      const [newProjects, newGroups] = await Promise.all([
-                        (async () => {
-                          const newProjects = projects.filter(
-                            ({ path: source }) => source !== path
-                          );
-                          await updateProjects(newProjects, path);
-                          return newProjects;
-                        })(),
-                        (async () => {
-                          const newGroups = [...groups];
-                          let needUpdate: boolean = false;
-                          newGroups.forEach((group) => {
-                            if (group.name === version) {
-                              needUpdate = true;
-                              const projects = [...group.projects];
-                              group.projects = projects.filter(
-                                (proPath) => proPath !== path
-                              );
-                            }
-                          });
-                          needUpdate && (await updateGroups(newGroups));
-                          return needUpdate ? newGroups : undefined;
-                        })(),
-                      ]);
-                      setProjects(newProjects);
-                      newGroups && setGroups(newGroups);
-                    }}
+            <div className='flex items-center gap-1'>
+              <Button
+                size='sm'
+                variant='tag'
+                title={t('Open-Terminal')}
+                onClick={async () => {
+                  try {
+                    await openTerminal(path);
+                  } catch (err) {
+                    toast.error(err as string);
+                  }
+                }}
+              >
+                <DesktopIcon />
+              </Button>
+              <Button
+                size='sm'
+                variant='tag'
+                title={t('Open-Editor')}
+                onClick={async () => {
+                  try {
+                    await openEditor(path);
+                  } catch (err) {
+                    toast.error(err as string);
+                  }
+                }}
+              >
+                <CodeIcon />
+              </Button>
+              <DropdownMenu>
+                <DropdownMenuTrigger asChild>
+                  <Button
+                    size='sm'
+                    variant='tag'
+                    icon={<ChevronDownIcon />}
+                  >
+                    {t('More')}
+                  </Button>
+                </DropdownMenuTrigger>
+                <DropdownMenuContent className='min-w-8'>
+                  <DropdownMenuItem
+                    className='flex gap-2 cursor-pointer'
+                    onSelect={() => setVersionFilesProject(row.original)}
+                  >
+                    <FileIcon />
+                    {t('Version-Files')}
+                  </DropdownMenuItem>
+                  <DropdownMenuItem
+                    className='flex gap-2 cursor-pointer'
+                    disabled={!row.original.active}
+                    onSelect={() => onDetectVersion(row.original)}
+                  >
+                    <MixIcon />
+                    {t('Detect-Version')}
+                  </DropdownMenuItem>
+                  <DropdownMenuItem
+                    className='flex gap-2 cursor-pointer'
+                    disabled={!row.original.active}
+                    onSelect={() => setScriptsProject(row.original)}
+                  >
+                    <PlayIcon />
+                    {t('Scripts')}
+                  </DropdownMenuItem>
+                  <DropdownMenuItem
+                    className='flex gap-2 cursor-pointer'
+                    onSelect={() => setEnvProject(row.original)}
                   >
-                    {t('OK')}
-                  </AlertDialogAction>
-                </AlertDialogFooter>
-              </AlertDialogContent>
-            </AlertDialog>
+                    <GearIcon />
+                    {t('Env')}
+                  </DropdownMenuItem>
+                </DropdownMenuContent>
+              </DropdownMenu>
+              <AlertDialog>
+                <AlertDialogTrigger asChild>
+                  <Button size='sm' variant='tag'>
+                    <TrashIcon />
+                    {t('Remove')}
+                  </Button>
+                </AlertDialogTrigger>
+                <AlertDialogContent className='top-1/3'>
+                  <AlertDialogHeader>
+                    <AlertDialogTitle>{name}</AlertDialogTitle>
+                    <AlertDialogDescription>
+                      {t('Project-Delete')}
+                    </AlertDialogDescription>
+                  </AlertDialogHeader>
+                  <AlertDialogFooter>
+                    <AlertDialogCancel>{t('Cancel')}</AlertDialogCancel>
+                    <AlertDialogAction
+                      onClick={async () => {
+                        const [newProjects, newGroups] = await Promise.all([
+                          (async () => {
+                            const newProjects = projects.filter(
+                              ({ path: source }) => source !== path
+                            );
+                            await updateProjects(newProjects, path);
+                            return newProjects;
+                          })(),
+                          (async () => {
+                            const newGroups = [...groups];
+                            let needUpdate: boolean = false;
+                            newGroups.forEach((group) => {
+                              if (group.name === version) {
+                                needUpdate = true;
+                                const projects = [...group.projects];
+                                group.projects = projects.filter(
+                                  (projectId) => projectId !== id
+                                );
+                              }
+                            });
+                            needUpdate && (await updateGroups(newGroups));
+                            return needUpdate ? newGroups : undefined;
+                          })(),
+                        ]);
+                        setProjects(newProjects);
+                        newGroups && setGroups(newGroups);
+                      }}
+                    >
+                      {t('OK')}
+                    </AlertDialogAction>
+                  </AlertDialogFooter>
+                </AlertDialogContent>
+              </AlertDialog>
+            </div>
           );
         },
       },
     ],
-    [t, projects, installedVersions.length, groups.length]
+    [t, projects, installedVersions.length, groups.length, aliases]
   );
 
   // add project (multiple)
@@ -354,10 +510,86 @@ export const Component: React.FC = () => {
 
     const newProjects = [...addedProjects, ...projects];
     setProjects(newProjects);
-    updateProjects(newProjects);
+    // reload the projects with the ids assigned when saved, the groups refer to them
+    updateProjects(newProjects).then(async () =>
+      setProjects((await projectList()) || [])
+    );
+    // write the version detected from the other version files to `.nvmdrc`
+    pInfo.forEach(({ path, version, source }) => {
+      if (
+        version &&
+        source !== '.nvmdrc' &&
+        addedProjects.find((project) => project.path === path)
+      ) {
+        syncProjectVersion(path, version);
+      }
+    });
     return;
   };
 
+  // scan a directory & import the projects found
+  const onScanProjects = async () => {
+    const pInfo = await scanProjects();
+    if (!pInfo) return;
+    if (!pInfo.length) {
+      toast.info(t('Scan-Projects-Empty'));
+      return;
+    }
+
+    toast.info(t('Scan-Projects-Found', { count: pInfo.length }), {
+      description: pInfo.map(({ path }) => path).join('\n'),
+      duration: Infinity,
+      action: {
+        label: t('Import'),
+        onClick: async () => {
+          try {
+            const added = await importProjects(pInfo);
+            setProjects((previous) => [...added, ...previous]);
+            toast.success(t('Scan-Projects-Imported', { count: added.length }));
+          } catch (err) {
+            toast.error(err as string);
+          }
+        },
+      },
+    });
+  };
+
+  // check the projects & fix the issues found
+  const onCheckHealth = async () => {
+    const issues = await projectHealth();
+    if (!issues.length) {
+      toast.success(t('Project-Health-Ok'));
+      return;
+    }
+
+    const kinds = [...new Set(issues.map(({ kind }) => kind))];
+    toast.warning(t('Project-Health-Issues', { count: issues.length }), {
+      description: issues
+        .map(({ name, kind }) => `${name}: ${t(`Health-${kind}`)}`)
+        .join('\n'),
+      duration: Infinity,
+      action: {
+        label: t('Fix'),
+        onClick: async () => {
+          try {
+            const left = await fixProjectHealth(kinds);
+            const [allProjects, allGroups] = await Promise.all([
+              projectList(),
+              groupList(),
+            ]);
+            setProjects(allProjects);
+            setGroups(allGroups);
+            left.length
+              ? toast.warning(t('Project-Health-Issues', { count: left.length }))
+              : toast.success(t('Project-Health-Ok'));
+          } catch (err) {
+            toast.error(err as string);
+          }
+        },
+      },
+    });
+  };
+
   const reorderRow = (draggedRowIndex: number, targetRowIndex: number) => {
     setProjects((previous) => {
       previous.splice(
@@ -376,14 +608,17 @@ export const Component: React.FC = () => {
   const onPageReload = async () => {
     setLoading(true);
     try {
-      const [allProjects, allGroups, installedVersions] = await Promise.all([
-        projectList(true),
-        groupList(true),
-        installedList(),
-      ]);
+      const [allProjects, allGroups, installedVersions, allAliases] =
+        await Promise.all([
+          projectList(true),
+          groupList(true),
+          installedList(),
+          aliasList(true),
+        ]);
       setProjects(allProjects);
       setGroups(allGroups);
       setInstalledVersions(installedVersions);
+      setAliases(allAliases);
       toast.success(t('Refresh-successful'));
     } finally {
       setLoading(false);
@@ -422,11 +657,49 @@ export const Component: React.FC = () => {
                 >
                   {t('Add-Project')}
                 </Button>
+                <Button
+                  size='sm'
+                  className='h-7 text-sm'
+                  icon={<MagnifyingGlassIcon />}
+                  onClick={onScanProjects}
+                >
+                  {t('Scan-Projects')}
+                </Button>
+                <Button
+                  size='sm'
+                  className='h-7 text-sm'
+                  icon={<ExclamationTriangleIcon />}
+                  onClick={onCheckHealth}
+                >
+                  {t('Project-Health')}
+                </Button>
               </div>
             </div>
           )}
           reorderRow={reorderRow}
         />
+        <VersionFiles
+          project={versionFilesProject}
+          onOpenChange={(open) => !open && setVersionFilesProject(undefined)}
+          onSubmit={(project) =>
+            setProjects((previous) =>
+              previous.map((item) => (item.id === project.id ? project : item))
+            )
+          }
+        />
+        <Scripts
+          project={scriptsProject}
+          onOpenChange={(open) => !open && setScriptsProject(undefined)}
+        />
+        <Env
+          project={envProject}
+          onOpenChange={(open) => !open && setEnvProject(undefined)}
+          onSubmit={(project) =>
+            setProjects((previous) =>
+              previous.map((item) => (item.id === project.id ? project : item))
+            )
+          }
+        />
       </div>
     </DndProvider>
   );
@@ -0,0 +1,272 @@
+import { useEffect, useRef, useState } from 'react';
+import {
+  Button,
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+  Tag,
+} from '@/components/ui';
+import {
+  CrossCircledIcon,
+  PlayIcon,
+  ReloadIcon,
+  StopIcon,
+  TrashIcon,
+} from '@radix-ui/react-icons';
+
+import { toast } from 'sonner';
+import { useTranslation } from 'react-i18next';
+import { cn } from '@/lib/utils';
+import { getCurrent } from '@/services/api';
+import {
+  processList,
+  processOutput,
+  projectScripts,
+  removeProcess,
+  restartProcess,
+  runScript,
+  stopProcess,
+} from '@/services/cmds';
+
+// the backend keeps the same number of lines
+const MAX_LINES = 500;
+
+const STATUS_COLORS = {
+  running: 'lime',
+  exited: 'neutral',
+  stopped: 'amber',
+} as const;
+
+type Props = {
+  project?: Nvmd.Project;
+  onOpenChange: (open: boolean) => void;
+};
+
+export const Scripts: React.FC<Props> = ({ project, onOpenChange }) => {
+  const [scripts, setScripts] = useState<Nvmd.Script[]>([]);
+  const [processes, setProcesses] = useState<Nvmd.ProcessInfo[]>([]);
+  // the process whose output is shown
+  const [selected, setSelected] = useState<string>();
+  const [output, setOutput] = useState<Nvmd.OutputLine[]>([]);
+
+  const selectedRef = useRef<string>();
+  const outputRef = useRef<HTMLPreElement>(null);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    if (!project) return;
+
+    setSelected(undefined);
+    Promise.all([projectScripts(project.path), processList(project.path)])
+      .then(([scripts, processes]) => {
+        setScripts(scripts);
+        setProcesses(processes);
+      })
+      .catch((err) => {
+        setScripts([]);
+        setProcesses([]);
+        toast.error(err as string);
+      });
+  }, [project]);
+
+  useEffect(() => {
+    selectedRef.current = selected;
+    if (!selected) {
+      setOutput([]);
+      return;
+    }
+
+    processOutput(selected)
+      .then(setOutput)
+      .catch(() => setOutput([]));
+  }, [selected]);
+
+  useEffect(() => {
+    const unlisted = [
+      getCurrent().listen<Nvmd.OutputLine>(
+        'on-process-output',
+        ({ payload }) => {
+          if (payload.id !== selectedRef.current) return;
+
+          setOutput((previous) => [...previous, payload].slice(-MAX_LINES));
+        }
+      ),
+      getCurrent().listen<Nvmd.ProcessInfo>(
+        'on-process-update',
+        ({ payload }) =>
+          setProcesses((previous) =>
+            previous.map((process) =>
+              process.id === payload.id ? payload : process
+            )
+          )
+      ),
+    ];
+
+    return () => {
+      unlisted.forEach((unlisten) => unlisten.then((fn) => fn()));
+    };
+  }, []);
+
+  // keep the latest output in view
+  useEffect(() => {
+    outputRef.current?.scrollTo({ top: outputRef.current.scrollHeight });
+  }, [output.length]);
+
+  const onRun = async (script: string) => {
+    if (!project) return;
+
+    try {
+      const process = await runScript(project.path, script);
+      setProcesses((previous) => [...previous, process]);
+      setSelected(process.id);
+    } catch (err) {
+      toast.error(err as string);
+    }
+  };
+
+  const onStop = async (id: string) => {
+    try {
+      await stopProcess(id);
+    } catch (err) {
+      toast.error(err as string);
+    }
+  };
+
+  const onRestart = async (id: string) => {
+    try {
+      const process = await restartProcess(id);
+      setProcesses((previous) =>
+        previous.map((item) => (item.id === id ? process : item))
+      );
+      // the output is cleared when restarted
+      id === selectedRef.current ? setOutput([]) : setSelected(id);
+    } catch (err) {
+      toast.error(err as string);
+    }
+  };
+
+  const onRemove = async (id: string) => {
+    try {
+      await removeProcess(id);
+      setProcesses((previous) => previous.filter((item) => item.id !== id));
+      id === selectedRef.current && setSelected(undefined);
+    } catch (err) {
+      toast.error(err as string);
+    }
+  };
+
+  return (
+    <Dialog open={!!project} onOpenChange={onOpenChange}>
+      <DialogContent className='max-w-3xl'>
+        <DialogHeader>
+          <DialogTitle>{t('Scripts')}</DialogTitle>
+          <DialogDescription>{project?.name}</DialogDescription>
+        </DialogHeader>
+        <div className='space-y-3'>
+          <div className='flex flex-wrap gap-2'>
+            {scripts.length ? (
+              scripts.map(({ name, command }) => (
+                <Button
+                  key={name}
+                  size='sm'
+                  variant='tag'
+                  title={command}
+                  icon={<PlayIcon />}
+                  onClick={() => onRun(name)}
+                >
+                  {name}
+                </Button>
+              ))
+            ) : (
+              <span className='text-sm text-muted-foreground'>
+                {t('Scripts-None')}
+              </span>
+            )}
+          </div>
+          {processes.length ? (
+            <div className='space-y-1'>
+              {processes.map(({ id, script, version, status, code }) => (
+                <div
+                  key={id}
+                  className={cn(
+                    'flex items-center justify-between px-2 py-1 text-sm rounded-md cursor-pointer hover:bg-accent',
+                    { 'bg-accent': id === selected }
+                  )}
+                  onClick={() => setSelected(id)}
+                >
+                  <span className='flex items-center gap-2'>
+                    <span className='font-medium'>{script}</span>
+                    <span className='text-muted-foreground'>v{version}</span>
+                    <Tag color={STATUS_COLORS[status]}>
+                      {t(`Process-${status}`)}
+                      {status === 'exited' && code != null
+                        ? ` (${code})`
+                        : null}
+                    </Tag>
+                  </span>
+                  <span
+                    className='flex items-center gap-1'
+                    onClick={(evt) => evt.stopPropagation()}
+                  >
+                    {status === 'running' ? (
+                      <Button
+                        size='sm'
+                        variant='ghost'
+                        title={t('Stop')}
+                        icon={<StopIcon />}
+                        onClick={() => onStop(id)}
+                      />
+                    ) : null}
+                    <Button
+                      size='sm'
+                      variant='ghost'
+                      title={t('Restart')}
+                      icon={<ReloadIcon />}
+                      onClick={() => onRestart(id)}
+                    />
+                    {status !== 'running' ? (
+                      <Button
+                        size='sm'
+                        variant='ghost'
+                        title={t('Remove')}
+                        icon={<TrashIcon />}
+                        onClick={() => onRemove(id)}
+                      />
+                    ) : null}
+                  </span>
+                </div>
+              ))}
+            </div>
+          ) : null}
+          {selected ? (
+            <div className='relative'>
+              <pre
+                ref={outputRef}
+                className='h-64 p-2 text-xs overflow-auto rounded-md bg-muted'
+              >
+                {output.map(({ stream, line }, index) => (
+                  <div
+                    key={index}
+                    className={cn({ 'text-red-500': stream === 'stderr' })}
+                  >
+                    {line}
+                  </div>
+                ))}
+              </pre>
+              <Button
+                className='absolute top-1 right-1'
+                size='sm'
+                variant='ghost'
+                icon={<CrossCircledIcon />}
+                onClick={() => setSelected(undefined)}
+              />
+            </div>
+          ) : null}
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+};
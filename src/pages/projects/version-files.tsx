@@ -0,0 +1,111 @@
+import { useEffect, useState } from 'react';
+import {
+  Button,
+  Checkbox,
+  Dialog,
+  DialogClose,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Label,
+  Switch,
+} from '@/components/ui';
+
+import { toast } from 'sonner';
+import { useTranslation } from 'react-i18next';
+import { updateProjectVersionFiles } from '@/services/cmds';
+
+// `.nvmdrc` is always kept up to date, the `nvmd` shim reads it
+export const EXTRA_VERSION_FILES: Nvmd.VersionFile[] = [
+  '.nvmrc',
+  '.node-version',
+  'package.json#volta',
+];
+
+type Props = {
+  project?: Nvmd.Project;
+  onOpenChange: (open: boolean) => void;
+  onSubmit: (project: Nvmd.Project) => void;
+};
+
+export const VersionFiles: React.FC<Props> = ({
+  project,
+  onOpenChange,
+  onSubmit,
+}) => {
+  const [global, setGlobal] = useState<boolean>(true);
+  const [files, setFiles] = useState<Nvmd.VersionFile[]>([]);
+  const [loading, setLoading] = useState<boolean>(false);
+
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    setGlobal(!project?.versionFiles);
+    setFiles(project?.versionFiles || []);
+  }, [project]);
+
+  const onSave = async () => {
+    if (!project?.id) return;
+
+    const versionFiles = global ? undefined : files;
+    setLoading(true);
+    try {
+      await updateProjectVersionFiles(project.id, versionFiles);
+      onSubmit({ ...project, versionFiles });
+      onOpenChange(false);
+    } catch (err) {
+      toast.error(err as string);
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  return (
+    <Dialog open={!!project} onOpenChange={onOpenChange}>
+      <DialogContent className='top-1/3'>
+        <DialogHeader>
+          <DialogTitle>{t('Version-Files')}</DialogTitle>
+          <DialogDescription>{project?.name}</DialogDescription>
+        </DialogHeader>
+        <div className='space-y-4'>
+          <div className='flex items-center gap-3'>
+            <Switch checked={global} onCheckedChange={setGlobal} />
+            <Label>{t('Version-Files-Global')}</Label>
+          </div>
+          <div className='flex flex-wrap items-center gap-4'>
+            <div className='flex items-center gap-2'>
+              <Checkbox checked disabled />
+              <Label>.nvmdrc</Label>
+            </div>
+            {EXTRA_VERSION_FILES.map((file) => (
+              <div key={file} className='flex items-center gap-2'>
+                <Checkbox
+                  checked={files.includes(file)}
+                  disabled={global}
+                  onCheckedChange={(checked) =>
+                    setFiles((previous) =>
+                      checked
+                        ? [...previous, file]
+                        : previous.filter((item) => item !== file)
+                    )
+                  }
+                />
+                <Label>{file}</Label>
+              </div>
+            ))}
+          </div>
+        </div>
+        <DialogFooter>
+          <DialogClose asChild>
+            <Button variant='secondary'>{t('Cancel')}</Button>
+          </DialogClose>
+          <Button loading={loading} onClick={onSave}>
+            {t('OK')}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+};
@@ -38,9 +38,10 @@ import {
 	installedList,
 	versionList,
 	uninstallNode,
+	versionUsages,
 	vSetCurrent,
 } from '@/services/cmds';
-import { checkSupportive } from '@/lib/utils';
+import { checkSupportive, splitInstallName } from '@/lib/utils';
 import { getCurrent } from '@/services/api';
 
 dayjs.extend(localizedFormat);
@@ -101,6 +102,13 @@ export const Versions: React.FC = () => {
 		fetcher();
 	}, [directory]);
 
+	// the archs of the side-by-side builds of the version, installed as `{version}-{arch}`
+	const installedArchs = (version: string) =>
+		installedVersions
+			.map(splitInstallName)
+			.filter(([name, arch]) => !!arch && `v${name}` === version)
+			.map(([, arch]) => arch!);
+
 	const columns: ColumnDef<Nvmd.Version>[] = useMemo(() => {
 		const { version: latest } = versions[0] || { version: '' };
 		return [
@@ -206,8 +214,10 @@ export const Versions: React.FC = () => {
 					const rets = filterValue.map((value) => {
 						switch (value) {
 							case 'Installed': {
-								return !!installedVersions.find(
-									(installed) => version === `v${installed}`
+								return (
+									!!installedVersions.find(
+										(installed) => version === `v${installed}`
+									) || !!installedArchs(version).length
 								);
 							}
 							case 'Supported': {
@@ -229,11 +239,36 @@ export const Versions: React.FC = () => {
 					const installed = installedVersions.find(
 						(installed) => `v${installed}` === version
 					);
+					const archs = installedArchs(version);
+					const archTags = archs.map((arch) => (
+						<Tag key={arch} color='sky'>
+							{arch}
+						</Tag>
+					));
+
+					// the current version may be a side-by-side build
+					const isCurrent =
+						!!current &&
+						[
+							installed,
+							...archs.map((arch) => `${version.slice(1)}-${arch}`),
+						].includes(current);
+
+					if (isCurrent)
+						return (
+							<div className='flex gap-1 items-center'>
+								<Tag color='lime'>{t('Current')}</Tag>
+								{archTags}
+							</div>
+						);
 
-					if (installed && current && version === `v${current}`)
-						return <Tag color='lime'>{t('Current')}</Tag>;
-
-					if (installed) return <Tag color='purple'>{t('Installed')}</Tag>;
+					if (installed || archs.length)
+						return (
+							<div className='flex gap-1 items-center'>
+								<Tag color='purple'>{t('Installed')}</Tag>
+								{archTags}
+							</div>
+						);
 
 					const support = checkSupportive(files);
 					if (!support) return <Tag color='rose'>{t('Not-Supported')}</Tag>;
@@ -281,21 +316,45 @@ export const Versions: React.FC = () => {
 									<DropdownMenuItem
 										className='flex gap-2 text-red-600 focus:text-red-500 cursor-pointer'
 										onSelect={async () => {
-											try {
-												await uninstallNode(
-													version.slice(1),
-													version.includes(current)
+											const onUninstall = async (force: boolean) => {
+												try {
+													await uninstallNode(
+														version.slice(1),
+														version.includes(current),
+														force
+													);
+													const [currentVersion, versions] = await Promise.all([
+														vCurrent(),
+														installedList(true),
+													]);
+													setCurrent(currentVersion);
+													setInstalledVersions(versions);
+													toast.success(t('Tip-Uninstall', { version }));
+												} catch (err) {
+													toast.error(err);
+												}
+											};
+
+											// confirm before uninstalling a version still in use
+											const usages = await versionUsages(
+												version.slice(1)
+											).catch(() => []);
+											if (usages.length) {
+												toast.warning(
+													t('Tip-Uninstall-Used', {
+														version,
+														usages: usages.map(({ name }) => name).join(', '),
+													}),
+													{
+														action: {
+															label: t('Uninstall'),
+															onClick: () => onUninstall(true),
+														},
+													}
 												);
-												const [currentVersion, versions] = await Promise.all([
-													vCurrent(),
-													installedList(true),
-												]);
-												setCurrent(currentVersion);
-												setInstalledVersions(versions);
-												toast.success(t('Tip-Uninstall', { version }));
-											} catch (err) {
-												toast.error(err);
+												return;
 											}
+											onUninstall(false);
 										}}
 									>
 										<CrossCircledIcon />
@@ -358,7 +417,10 @@ export const Versions: React.FC = () => {
 							const { version, files } = facetedRowModel.flatRows[i]!.original;
 
 							let key: string | undefined;
-							if (installedVersions.includes(version.slice(1)))
+							if (
+								installedVersions.includes(version.slice(1)) ||
+								installedArchs(version).length
+							)
 								key = 'Installed';
 
 							if (key === void 0 && checkSupportive(files)) key = 'Supported';
@@ -475,7 +537,11 @@ export const Versions: React.FC = () => {
 					getFacetedUniqueValues={getFacetedUniqueValues}
 				/>
 			</div>
-			<Modal ref={modal} onRefrresh={onInstalledRefresh} />
+			<Modal
+				ref={modal}
+				installed={installedVersions}
+				onRefrresh={onInstalledRefresh}
+			/>
 		</>
 	);
 };
@@ -35,14 +35,20 @@ export type Ref = {
 };
 
 type Props = {
+	/** the installed versions, their global packages can be migrated */
+	installed: string[];
 	onRefrresh: () => void;
 };
 
 const archs = ['arm64', 'x64', 'x86'];
 
-export const Modal = forwardRef<Ref, Props>(({ onRefrresh }, ref) => {
+// the placeholder of not migrating, the select item value can not be empty
+const NO_MIGRATE = 'none';
+
+export const Modal = forwardRef<Ref, Props>(({ installed, onRefrresh }, ref) => {
 	const [open, setOpen] = useState<boolean>(false);
 	const [asDefault, setAsDefault] = useState<boolean>(true);
+	const [migrateFrom, setMigrateFrom] = useState<string>(NO_MIGRATE);
 	const [loading, setLoading] = useState<boolean>(false);
 	const [path, setPath] = useState<string>();
 	const [, updater] = useState<number>(0);
@@ -119,7 +125,8 @@ export const Modal = forwardRef<Ref, Props>(({ onRefrresh }, ref) => {
 		try {
 			const path = await installNode(
 				record.current!.version.slice(1),
-				arch.current!.innerText
+				arch.current!.innerText,
+				migrateFrom === NO_MIGRATE ? undefined : migrateFrom
 			);
 
 			progress.current = {
@@ -147,7 +154,10 @@ export const Modal = forwardRef<Ref, Props>(({ onRefrresh }, ref) => {
 
 	const onFinish = async () => {
 		if (asDefault) {
-			await vSetCurrent(record.current!.version.slice(1));
+			// the installation directory name, `{version}-{arch}` for the non-native arch
+			const name =
+				path?.split(/[\\/]/).pop() || record.current!.version.slice(1);
+			await vSetCurrent(name);
 		}
 		onRefrresh();
 		setOpen(false);
@@ -162,6 +172,7 @@ export const Modal = forwardRef<Ref, Props>(({ onRefrresh }, ref) => {
 		archOption.current = archs;
 		setPath(undefined);
 		setAsDefault(true);
+		setMigrateFrom(NO_MIGRATE);
 	};
 
 	return (
@@ -233,6 +244,29 @@ export const Modal = forwardRef<Ref, Props>(({ onRefrresh }, ref) => {
 								</SelectGroup>
 							</SelectContent>
 						</Select>
+						{installed.length ? (
+							<Select
+								disabled={loading}
+								value={migrateFrom}
+								onValueChange={setMigrateFrom}
+							>
+								<SelectTrigger className='w-48 h-6'>
+									<SelectValue />
+								</SelectTrigger>
+								<SelectContent>
+									<SelectGroup>
+										<SelectItem value={NO_MIGRATE}>
+											{t('Migrate-None')}
+										</SelectItem>
+										{installed.map((version) => (
+											<SelectItem key={version} value={version}>
+												{t('Migrate-From', { version: `v${version}` })}
+											</SelectItem>
+										))}
+									</SelectGroup>
+								</SelectContent>
+							</Select>
+						) : null}
 						<div className='h-6 items-top flex items-center space-x-2'>
 							<Checkbox
 								id='as-default'
@@ -17,6 +17,19 @@ export async function updateSettings(settings: Nvmd.Setting) {
 	return invoke<void>('update_settings', { settings });
 }
 
+/**
+ * @description: Probe the mirrors and rank them by speed
+ * @param {string[]} mirrors user mirror urls (the built-in mirrors are always included)
+ * @param {boolean} apply whether to write the fastest mirror into settings
+ * @return {Promise<Array<Nvmd.MirrorBenchmark>>} ranked results
+ */
+export async function benchmarkMirrors(mirrors?: string[], apply: boolean = false) {
+	return invoke<Array<Nvmd.MirrorBenchmark>>('benchmark_mirrors', {
+		mirrors,
+		apply,
+	});
+}
+
 /**
  * @description: Get the current global node version
  * @param {boolean} fetch whether to read the latest value from the file
@@ -28,7 +41,7 @@ export function vCurrent(fetch: boolean = false) {
 
 /**
  * @description: Set the globally effective node version
- * @param {string} version node version number
+ * @param {string} version node version number, alias or installation name (`{version}-{arch}`)
  * @return {Promise<void>}	Promise-void
  */
 export function vSetCurrent(version: string) {
@@ -44,6 +57,14 @@ export function versionList(fetch: boolean = false) {
 	return invoke<Nvmd.Versions>('version_list', { fetch });
 }
 
+/**
+ * @description: Get the newer security releases of the versions in use
+ * @return {Promise<Array<Nvmd.SecurityAlert>>} security alerts
+ */
+export function securityReport() {
+	return invoke<Array<Nvmd.SecurityAlert>>('security_report');
+}
+
 /**
  * @description: Get a list of installed nodes
  * @param {boolean} fetch Whether to read the latest data
@@ -53,14 +74,29 @@ export function installedList(fetch: boolean = false) {
 	return invoke<Array<string>>('installed_list', { fetch });
 }
 
+/**
+ * @description: Get a list of installed nodes with metadata
+ * @param {boolean} fetch Whether to read the latest data
+ * @return {Promise<Array<Nvmd.InstalledVersion>>} installed versions with metadata
+ */
+export function installedVersions(fetch: boolean = false) {
+	return invoke<Array<Nvmd.InstalledVersion>>('installed_versions', { fetch });
+}
+
 /**
  * @description: Download Node
  * @param {string} version node version
- * @param {string} arch	node architecture
+ * @param {string} arch	node architecture, a non-default arch is installed as `{version}-{arch}`
+ * @param {string} migrateFrom reinstall the global packages of this version after installation,
+ * the progress is emitted by `on-packages-progress` & the report by `on-packages-migrated`
  * @return {Promise<string>}	The file path where the downloaded node is saved
  */
-export function installNode(version: string, arch?: string) {
-	return invoke<string>('install_node', { version, arch });
+export function installNode(
+	version: string,
+	arch?: string,
+	migrateFrom?: string
+) {
+	return invoke<string>('install_node', { version, arch, migrateFrom });
 }
 
 /**
@@ -71,14 +107,152 @@ export function installNodeCancel() {
 	return invoke<void>('install_node_cancel');
 }
 
+/**
+ * @description: Get the versions used by the default, projects, groups & aliases but not installed
+ * @return {Promise<Nvmd.MissingVersion[]>}
+ */
+export function missingVersions() {
+	return invoke<Nvmd.MissingVersion[]>('missing_versions');
+}
+
+/**
+ * @description: Install the missing versions one by one,
+ * the queue progress is emitted by `on-required-progress` & the download progress by `on-node-progress`
+ * @param {string[]} names the installation names to install, all the missing versions when omitted
+ * @return {Promise<Nvmd.RequiredInstall[]>}
+ */
+export function installMissing(names?: string[]) {
+	return invoke<Nvmd.RequiredInstall[]>('install_missing', { names });
+}
+
 /**
  * @description	uninstall node
  * @param {string} version version number
  * @param {boolean} current whether the version to be uninstalled is the currently used version
- * @returns {Promise<void>} Promise<void>
+ * @param {boolean} force uninstall even if the version is still used
+ * @param {string} reassign the version to switch the dependents to
+ * @returns {Promise<Nvmd.Usage[]>} the dependents of the version
+ */
+export function uninstallNode(
+	version: string,
+	current: boolean = false,
+	force: boolean = false,
+	reassign?: string
+) {
+	return invoke<Nvmd.Usage[]>('uninstall_node', {
+		version,
+		current,
+		force,
+		reassign,
+	});
+}
+
+/**
+ * @description: Get the dependents of the version (default, projects, groups & aliases)
+ * @param {string} version version number
+ * @return {Promise<Nvmd.Usage[]>}
+ */
+export function versionUsages(version: string) {
+	return invoke<Nvmd.Usage[]>('version_usages', { version });
+}
+
+/**
+ * @description: Reinstall the global packages of a version into another version,
+ * the progress is emitted by `on-packages-progress`
+ * @param {string} from the source version
+ * @param {string} to the target version
+ * @param {string[]} packages the package names to migrate, all when omitted
+ * @return {Promise<Nvmd.PackageResult[]>}
+ */
+export function migratePackages(from: string, to: string, packages?: string[]) {
+	return invoke<Nvmd.PackageResult[]>('migrate_packages', {
+		from,
+		to,
+		packages,
+	});
+}
+
+/**
+ * @description: Get the global packages of the version
+ * @param {string} version version number
+ * @return {Promise<Nvmd.GlobalPackage[]>}
+ */
+export function globalPackages(version: string) {
+	return invoke<Nvmd.GlobalPackage[]>('global_packages', { version });
+}
+
+/**
+ * @description: Uninstall the global package with the npm of the version
+ * @param {string} version version number
+ * @param {string} name package name
+ * @return {Promise<void>}
+ */
+export function uninstallPackage(version: string, name: string) {
+	return invoke<void>('uninstall_package', { version, name });
+}
+
+/**
+ * @description: Update the global package with the npm of the version
+ * @param {string} version version number
+ * @param {string} name package name
+ * @param {string} range the version range to install, `latest` when omitted
+ * @return {Promise<void>}
+ */
+export function updatePackage(version: string, name: string, range?: string) {
+	return invoke<void>('update_package', { version, name, range });
+}
+
+/**
+ * @description: Remove the unused versions & the versions beyond the retention policy
+ * @param {Nvmd.PruneOptions} options use `dryRun` to get the report without removing
+ * @return {Promise<Nvmd.PruneReport>}
+ */
+export function pruneVersions(options: Nvmd.PruneOptions) {
+	return invoke<Nvmd.PruneReport>('prune_versions', { options });
+}
+
+/**
+ * @description: Verify the integrity of installed versions
+ * @return {Promise<Array<Nvmd.VersionIntegrity>>} integrity report
+ */
+export function verifyInstalled() {
+	return invoke<Array<Nvmd.VersionIntegrity>>('verify_installed');
+}
+
+/**
+ * @description: Reinstall node, the old files are restored when it fails
+ * @param {string} version node version
+ * @return {Promise<string>}	The file path where the node is saved
+ */
+export function reinstallNode(version: string) {
+	return invoke<string>('reinstall_node', { version });
+}
+
+/**
+ * @description: Find node installations of other version managers (nvm, fnm, volta, n, asdf)
+ * @return {Promise<Array<Nvmd.ExternalVersion>>} installations found
  */
-export function uninstallNode(version: string, current: boolean = false) {
-	return invoke<void>('uninstall_node', { version, current });
+export function discoverVersions() {
+	return invoke<Array<Nvmd.ExternalVersion>>('discover_versions');
+}
+
+/**
+ * @description: Import node installations of other version managers
+ * @param {Array<Nvmd.ExternalVersion>} list installations to import
+ * @param {Nvmd.ImportMode} mode copy, hardlink or register (symbolic link)
+ * @param {boolean} setDefault whether to use the tool's default as the global version
+ * @return {Promise<Array<Nvmd.ImportResult>>} result of each installation
+ */
+export function importVersions(
+	list: Nvmd.ExternalVersion[],
+	mode: Nvmd.ImportMode,
+	setDefault: boolean = false
+) {
+	return invoke<Array<Nvmd.ImportResult>>('import_versions', {
+		list,
+		mode,
+		setDefault,
+	});
 }
 
 /**
@@ -98,6 +272,53 @@ export function selectProjects() {
 	return invoke<Array<Nvmd.PInfo>>('select_projects');
 }
 
+/**
+ * @description: Scan the directory for projects, skipping `node_modules`, hidden & git ignored directories
+ * @param {string} root the directory to scan, a directory is picked when omitted
+ * @param {number} depth the max depth to scan, `4` by default
+ * @return {Promise<Array<Nvmd.PInfo> | null>} the projects not added yet, `null` when cancelled
+ */
+export function scanProjects(root?: string, depth?: number) {
+	return invoke<Array<Nvmd.PInfo> | null>('scan_projects', { root, depth });
+}
+
+/**
+ * @description: Import the scanned projects to the front of the project list
+ * @param {Array<Nvmd.PInfo>} list the scanned projects
+ * @return {Promise<Nvmd.Project[]>} the projects added
+ */
+export function importProjects(list: Nvmd.PInfo[]) {
+	return invoke<Nvmd.Project[]>('import_projects', { list });
+}
+
+/**
+ * @description: Detect the node version of the project from `.nvmdrc`, `.nvmrc`, `.node-version` & `package.json`
+ * @param {string} path project floder path
+ * @return {Promise<Nvmd.DetectedVersion | null>}
+ */
+export function detectProjectVersion(path: string) {
+	return invoke<Nvmd.DetectedVersion | null>('detect_project_version', {
+		path,
+	});
+}
+
+/**
+ * @description: Check the projects for missing paths, uninstalled versions, missing groups & drifted version files
+ * @return {Promise<Nvmd.HealthIssue[]>}
+ */
+export function projectHealth() {
+	return invoke<Nvmd.HealthIssue[]>('project_health');
+}
+
+/**
+ * @description: Fix the project issues of the kinds
+ * @param {Nvmd.HealthIssueKind[]} kinds the kinds of issues to fix
+ * @return {Promise<Nvmd.HealthIssue[]>} the issues left
+ */
+export function fixProjectHealth(kinds: Nvmd.HealthIssueKind[]) {
+	return invoke<Nvmd.HealthIssue[]>('fix_project_health', { kinds });
+}
+
 /**
  * @description: Update projects data
  * @param {Array<Nvmd.Project>} list projects list
@@ -118,13 +339,118 @@ export function syncProjectVersion(path: string, version: string) {
 }
 
 /**
- * @description: Batch update project version
+ * @description: Batch update project version, the version files & the project list are updated together,
+ * nothing is changed when any project fails
  * @param {string[]} paths project floder paths
  * @param {string} version node version
+ * @param {string} record the version recorded in the project list (e.g. the group name), `version` when omitted
+ * @return {Promise<Nvmd.BatchUpdate>} the result of each project
+ */
+export function batchUpdateProjectVersion(
+	paths: string[],
+	version: string,
+	record?: string
+) {
+	return invoke<Nvmd.BatchUpdate>('batch_update_project_version', {
+		paths,
+		version,
+		record,
+	});
+}
+
+/**
+ * @description: Update the version files kept up to date for the project besides `.nvmdrc`,
+ * the project version is written into them
+ * @param {string} id project id
+ * @param {Nvmd.VersionFile[]} files the version files, the global setting when omitted
+ * @return {Promise<void>}
+ */
+export function updateProjectVersionFiles(
+	id: string,
+	files?: Nvmd.VersionFile[]
+) {
+	return invoke<void>('update_project_version_files', { id, files });
+}
+
+/**
+ * @description: Update the environment variables of the project,
+ * applied when running scripts or opening terminals for the project
+ * @param {string} id project id
+ * @param {Record<string, string>} env the environment variables, `PATH` is not allowed
+ * @param {boolean} envFile mirror the environment variables into `.nvmdenv` for the shim
+ * @return {Promise<void>}
+ */
+export function updateProjectEnv(
+	id: string,
+	env: Record<string, string>,
+	envFile: boolean
+) {
+	return invoke<void>('update_project_env', { id, env, envFile });
+}
+
+/**
+ * @description: Get the scripts of the project `package.json`
+ * @param {string} path project floder path
+ * @return {Promise<Nvmd.Script[]>}
+ */
+export function projectScripts(path: string) {
+	return invoke<Nvmd.Script[]>('project_scripts', { path });
+}
+
+/**
+ * @description: Run the npm script of the project with the project's node version,
+ * the output is emitted by `on-process-output` & the status changes by `on-process-update`
+ * @param {string} path project floder path
+ * @param {string} script script name
+ * @return {Promise<Nvmd.ProcessInfo>}
+ */
+export function runScript(path: string, script: string) {
+	return invoke<Nvmd.ProcessInfo>('run_script', { path, script });
+}
+
+/**
+ * @description: Stop the running process with its child processes
+ * @param {string} id process id
+ * @return {Promise<void>}
+ */
+export function stopProcess(id: string) {
+	return invoke<void>('stop_process', { id });
+}
+
+/**
+ * @description: Stop the process & run the script again, the output is cleared
+ * @param {string} id process id
+ * @return {Promise<Nvmd.ProcessInfo>}
+ */
+export function restartProcess(id: string) {
+	return invoke<Nvmd.ProcessInfo>('restart_process', { id });
+}
+
+/**
+ * @description: Remove the finished process
+ * @param {string} id process id
  * @return {Promise<void>}
  */
-export function batchUpdateProjectVersion(paths: string[], version: string) {
-	return invoke<void>('batch_update_project_version', { paths, version });
+export function removeProcess(id: string) {
+	return invoke<void>('remove_process', { id });
+}
+
+/**
+ * @description: Get the processes
+ * @param {string} path the processes of the project when set
+ * @return {Promise<Nvmd.ProcessInfo[]>}
+ */
+export function processList(path?: string) {
+	return invoke<Nvmd.ProcessInfo[]>('process_list', { path });
+}
+
+/**
+ * @description: Get the last output lines (500 at most) of the process
+ * @param {string} id process id
+ * @return {Promise<Nvmd.OutputLine[]>}
+ */
+export function processOutput(id: string) {
+	return invoke<Nvmd.OutputLine[]>('process_output', { id });
 }
 
 /**
@@ -146,13 +472,42 @@ export function updateGroups(list: Nvmd.Group[]) {
 }
 
 /**
- * @description: Update group version
+ * @description: Update group version & the version files of the member projects,
+ * nothing is changed when any member project fails
  * @param {string} name group name
  * @param {string} version version
- * @return {Promise<void>}
+ * @return {Promise<Nvmd.BatchUpdate>} the results of the member projects
  */
 export function updateGroupVersion(name: string, version: string) {
-	return invoke<void>('update_group_version', { name, version });
+	return invoke<Nvmd.BatchUpdate>('update_group_version', { name, version });
+}
+
+/**
+ * @description: Get aliases from locale file
+ * @param {boolean} fetch whether to read the latest value from the file
+ * @return {Promise<Nvmd.Aliases>}
+ */
+export function aliasList(fetch: boolean = false) {
+	return invoke<Nvmd.Aliases>('alias_list', { fetch });
+}
+
+/**
+ * @description: Create or repoint an alias, projects & groups using it are updated
+ * @param {string} name alias name
+ * @param {string} version full version or major/minor prefix, e.g. `18.19.0`, `14`
+ * @return {Promise<void>}
+ */
+export function setAlias(name: string, version: string) {
+	return invoke<void>('set_alias', { name, version });
+}
+
+/**
+ * @description: Remove an alias, fails when it is still used by projects or groups
+ * @param {string} name alias name
+ * @return {Promise<void>}
+ */
+export function removeAlias(name: string) {
+	return invoke<void>('remove_alias', { name });
 }
 
 /**
@@ -188,6 +543,24 @@ export function openDir(dir: string) {
 	return invoke<void>('open_dir', { dir });
 }
 
+/**
+ * @description: Open the terminal at the project with the project's node version first in `PATH`
+ * @param {string} path project path
+ * @return {Promise<void>}
+ */
+export function openTerminal(path: string) {
+	return invoke<void>('open_terminal', { path });
+}
+
+/**
+ * @description: Open the editor at the project with the project's node version first in `PATH`
+ * @param {string} path project path
+ * @return {Promise<void>}
+ */
+export function openEditor(path: string) {
+	return invoke<void>('open_editor', { path });
+}
+
 /**
  * @description: Exits the current instance of the app then relaunches it
  * @returns {Promise<void>}